@@ -5,7 +5,8 @@ mod error;
 mod types;
 
 use error::ReputationError;
-use types::{Attestation, DataKey, Profile};
+use types::DataKey;
+pub use types::{Attestation, Profile};
 
 const PROFILE_TTL_LEDGERS: u32 = 5_184_000;
 
@@ -15,6 +16,13 @@ pub struct AttestationRevokedEvent {
     pub revoked_by: Address,
 }
 
+#[contractevent(topics = ["profile", "reported"])]
+pub struct ProfileReportedEvent {
+    pub subject: Address,
+    pub reporter: Address,
+    pub score: i64,
+}
+
 #[contract]
 pub struct QuidReputationContract;
 
@@ -78,6 +86,8 @@ impl QuidReputationContract {
             PROFILE_TTL_LEDGERS,
         );
 
+        Self::count_active_attestation(&env, &attestation, true);
+
         Ok(attestation_id)
     }
 
@@ -110,6 +120,7 @@ impl QuidReputationContract {
         env.storage()
             .persistent()
             .set(&DataKey::Attestation(attestation_id), &attestation);
+        Self::count_active_attestation(&env, &attestation, false);
 
         env.storage().persistent().extend_ttl(
             &DataKey::Attestation(attestation_id),
@@ -139,6 +150,19 @@ impl QuidReputationContract {
             .has(&DataKey::Attestation(attestation_id))
     }
 
+    /// Whether `subject` holds an unrevoked attestation of `attestation_type`
+    /// issued by `issuer`. Anyone can issue attestations, so callers must name
+    /// the issuer they trust.
+    pub fn has_attestation(
+        env: Env,
+        subject: Address,
+        attestation_type: String,
+        issuer: Address,
+    ) -> bool {
+        let key = DataKey::ActiveAttestations(subject, attestation_type, issuer);
+        env.storage().persistent().get::<_, u32>(&key).unwrap_or(0) > 0
+    }
+
     // -------------------------------------------------------------------------
     // Profiles
     // -------------------------------------------------------------------------
@@ -150,8 +174,10 @@ impl QuidReputationContract {
             .ok_or(ReputationError::ProfileNotFound)
     }
 
+    /// Overwrite a profile. Only the admin may invoke this; day-to-day
+    /// updates come from registered reporters.
     pub fn set_profile(env: Env, profile: Profile) -> Result<(), ReputationError> {
-        profile.subject.require_auth();
+        Self::get_admin(env.clone())?.require_auth();
 
         env.storage()
             .persistent()
@@ -170,6 +196,140 @@ impl QuidReputationContract {
         env.storage().persistent().has(&DataKey::Profile(subject))
     }
 
+    // -------------------------------------------------------------------------
+    // Reporters
+    // -------------------------------------------------------------------------
+
+    /// Allow a contract such as `quid-store` to update profiles.
+    pub fn add_reporter(
+        env: Env,
+        caller: Address,
+        reporter: Address,
+    ) -> Result<(), ReputationError> {
+        Self::require_admin(&env, &caller)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Reporter(reporter), &true);
+        Ok(())
+    }
+
+    pub fn remove_reporter(
+        env: Env,
+        caller: Address,
+        reporter: Address,
+    ) -> Result<(), ReputationError> {
+        Self::require_admin(&env, &caller)?;
+
+        env.storage()
+            .instance()
+            .remove(&DataKey::Reporter(reporter));
+        Ok(())
+    }
+
+    pub fn is_reporter(env: Env, reporter: Address) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Reporter(reporter))
+            .unwrap_or(false)
+    }
+
+    /// Count a mission created by `subject`.
+    pub fn record_mission_created(
+        env: Env,
+        reporter: Address,
+        subject: Address,
+    ) -> Result<(), ReputationError> {
+        Self::require_reporter(&env, &reporter)?;
+
+        let mut profile = Self::load_or_default(&env, subject);
+        profile.missions_created = profile.missions_created.saturating_add(1);
+        Self::report_profile(&env, &reporter, &profile);
+        Ok(())
+    }
+
+    /// Count a rewarded submission by `subject` and raise their score.
+    pub fn record_mission_completed(
+        env: Env,
+        reporter: Address,
+        subject: Address,
+        score_delta: i64,
+    ) -> Result<(), ReputationError> {
+        Self::require_reporter(&env, &reporter)?;
+        if score_delta < 0 {
+            return Err(ReputationError::InvalidInput);
+        }
+
+        let mut profile = Self::load_or_default(&env, subject);
+        profile.missions_completed = profile.missions_completed.saturating_add(1);
+        profile.score = profile.score.saturating_add(score_delta);
+        Self::report_profile(&env, &reporter, &profile);
+        Ok(())
+    }
+
+    /// Lower the score of `subject` after a finalized slash.
+    pub fn record_slash(
+        env: Env,
+        reporter: Address,
+        subject: Address,
+        penalty: i64,
+    ) -> Result<(), ReputationError> {
+        Self::require_reporter(&env, &reporter)?;
+        if penalty < 0 {
+            return Err(ReputationError::InvalidInput);
+        }
+
+        let mut profile = Self::load_or_default(&env, subject);
+        profile.score = profile.score.saturating_sub(penalty);
+        Self::report_profile(&env, &reporter, &profile);
+        Ok(())
+    }
+
+    fn require_reporter(env: &Env, reporter: &Address) -> Result<(), ReputationError> {
+        reporter.require_auth();
+
+        if !Self::is_reporter(env.clone(), reporter.clone()) {
+            return Err(ReputationError::NotAuthorized);
+        }
+        Ok(())
+    }
+
+    fn report_profile(env: &Env, reporter: &Address, profile: &Profile) {
+        Self::store_profile(env, profile);
+
+        ProfileReportedEvent {
+            subject: profile.subject.clone(),
+            reporter: reporter.clone(),
+            score: profile.score,
+        }
+        .publish(env);
+    }
+
+    /// Track how many unrevoked attestations a subject holds from one issuer
+    /// for one type, so `has_attestation` is a single lookup.
+    fn count_active_attestation(env: &Env, attestation: &Attestation, issued: bool) {
+        let key = DataKey::ActiveAttestations(
+            attestation.subject.clone(),
+            attestation.attestation_type.clone(),
+            attestation.issuer.clone(),
+        );
+        let count: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        let count = if issued {
+            count + 1
+        } else {
+            count.saturating_sub(1)
+        };
+
+        if count == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &count);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, PROFILE_TTL_LEDGERS, PROFILE_TTL_LEDGERS);
+        }
+    }
+
     fn get_next_attestation_id(env: &Env) -> u64 {
         let mut count: u64 = env
             .storage()
@@ -29,4 +29,9 @@ pub enum DataKey {
     Profile(Address),
     Attestation(u64),
     AttestationCount,
+    /// Unrevoked attestations held by a subject, keyed by
+    /// (subject, attestation type, issuer).
+    ActiveAttestations(Address, String, Address),
+    /// Contracts allowed to update profiles.
+    Reporter(Address),
 }
@@ -19,4 +19,49 @@ pub enum QuidError {
     TreasuryNotSet = 14,
     StakeNotFound = 15,
     InsufficientAssetBalance = 16,
+    InvalidDeadline = 17,
+    DeadlinePassed = 18,
+    DeadlineNotReached = 19,
+    ReviewWindowOpen = 20,
+    InvalidTransition = 21,
+    PendingSubmissions = 22,
+    InvalidFee = 23,
+    BatchTooLarge = 24,
+    InvalidTier = 25,
+    TierFull = 26,
+    NothingToClaim = 27,
+    SlashNotFound = 28,
+    DisputeWindowClosed = 29,
+    DisputeWindowOpen = 30,
+    ReviewerNotFound = 31,
+    InsufficientReputation = 32,
+    ReputationNotSet = 33,
+    /// Asset requirement at index 0 is unmet; indices 1-3 follow.
+    AssetRequirement0Unmet = 34,
+    AssetRequirement1Unmet = 35,
+    AssetRequirement2Unmet = 36,
+    AssetRequirement3Unmet = 37,
+    NotAllowlisted = 38,
+    InvalidReveal = 39,
+    OutsideRevealWindow = 40,
+    AlreadyInitialized = 41,
+    NotInitialized = 42,
+    ContractPaused = 43,
+    /// A transfer into escrow delivered less than the requested amount.
+    TokenUnderDelivered = 44,
+    TokenNotVetted = 45,
+    /// An address allowlist is empty or longer than `MAX_ALLOWLIST_SIZE`.
+    InvalidAllowlist = 46,
+}
+
+impl QuidError {
+    /// Error naming the unmet asset requirement at `index`.
+    pub fn asset_requirement_unmet(index: u32) -> Self {
+        match index {
+            0 => QuidError::AssetRequirement0Unmet,
+            1 => QuidError::AssetRequirement1Unmet,
+            2 => QuidError::AssetRequirement2Unmet,
+            _ => QuidError::AssetRequirement3Unmet,
+        }
+    }
 }
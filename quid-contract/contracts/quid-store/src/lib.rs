@@ -1,12 +1,107 @@
 #![no_std]
-use soroban_sdk::{contract, contractevent, contractimpl, contracttype, Address, Env, String};
+use soroban_sdk::{
+    contract, contractevent, contractimpl, contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env,
+    IntoVal, String, TryFromVal, Val, Vec,
+};
 
 mod error;
+mod reputation;
 mod types;
 
 use error::QuidError;
+use reputation::ReputationClient;
 use soroban_sdk::token;
-use types::{DataKey, Mission, MissionStatus, Submission, SubmissionStatus};
+use types::{
+    Allowlist, AssetRequirement, ClaimableBalance, DataKey, FeeConfig, FeeMode, GateRule, Mission,
+    MissionRole, MissionStatus, MissionV1, RaffleDraw, ReputationGate, RewardTier, SlashRecord,
+    SlashStatus, Submission, SubmissionStatus, SubmissionV1, VersionedMission, VersionedSubmission,
+};
+
+/// Ledgers closed per day at ~5 seconds each.
+const DAY_IN_LEDGERS: u32 = 17_280;
+/// Every write keeps its entries, and the contract instance, alive for
+/// ~300 days. Entries are only extended once they drop below the threshold.
+const BUMP_AMOUNT: u32 = 300 * DAY_IN_LEDGERS;
+const LIFETIME_THRESHOLD: u32 = BUMP_AMOUNT - 30 * DAY_IN_LEDGERS;
+
+/// Storage schema written by this code; see `VersionedMission`.
+const SCHEMA_VERSION: u32 = 2;
+
+/// Upper bound on entries scanned by a single paginated query.
+const MAX_PAGE_SIZE: u32 = 50;
+
+/// Hard ceiling on the protocol fee: 10%.
+const MAX_FEE_BPS: u32 = 1_000;
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Seconds a hunter has to appeal a slash before it can be finalized.
+const DISPUTE_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+/// Score reported to the reputation contract for each rewarded submission.
+const REPUTATION_PAYOUT_SCORE: i64 = 10;
+/// Score removed by the reputation contract for each upheld slash.
+const REPUTATION_SLASH_PENALTY: i64 = 25;
+
+/// Maximum number of asset requirements on a single mission; each has its
+/// own `QuidError` code.
+const MAX_ASSET_REQUIREMENTS: u32 = 4;
+
+/// Maximum number of prize tiers on a single mission.
+const MAX_REWARD_TIERS: u32 = 10;
+
+/// Maximum number of delegated roles on a single mission, so `bump_mission`
+/// can extend all of them in one call.
+const MAX_REVIEWERS: u32 = 10;
+
+/// Maximum number of addresses in an `Allowlist::Addresses`, which is read in
+/// full on every submission. Larger invite lists should use a Merkle root.
+const MAX_ALLOWLIST_SIZE: u32 = 50;
+
+/// Upper bound on submissions settled by one batch call. Each costs a few
+/// ledger writes, so this keeps a full batch within Soroban's per-transaction
+/// entry limits (see the resource tests).
+const MAX_BATCH_SIZE: u32 = 10;
+
+/// Maximum number of eligible entrants in a single raffle. `draw_winners`
+/// reads every entrant's submission, so together with `MAX_RAFFLE_WINNERS`
+/// this keeps the draw within the per-transaction read limit.
+const MAX_RAFFLE_ENTRANTS: u32 = 50;
+
+/// Maximum number of prizes a raffle awards. Each winner is approved in the
+/// draw transaction at a few ledger writes apiece (see the resource tests).
+const MAX_RAFFLE_WINNERS: u32 = MAX_BATCH_SIZE;
+
+#[contractevent(topics = ["admin", "propose"])]
+pub struct AdminProposeEvent {
+    pub admin: Address,
+    pub pending_admin: Address,
+}
+
+#[contractevent(topics = ["admin", "accept"])]
+pub struct AdminAcceptEvent {
+    pub admin: Address,
+}
+
+#[contractevent(topics = ["contract", "pause"])]
+pub struct ContractPauseEvent {
+    pub paused: bool,
+}
+
+#[contractevent(topics = ["token", "allowlist"])]
+pub struct TokenAllowlistEvent {
+    pub enabled: bool,
+}
+
+#[contractevent(topics = ["token", "vet"])]
+pub struct TokenVetEvent {
+    pub token: Address,
+    pub vetted: bool,
+}
+
+#[contractevent(topics = ["contract", "upgrade"])]
+pub struct ContractUpgradeEvent {
+    pub wasm_hash: BytesN<32>,
+}
 
 #[contractevent(topics = ["mission", "create"])]
 pub struct MissionCreateEvent {
@@ -20,10 +115,95 @@ pub struct SubNewEvent {
     pub hunter: Address,
 }
 
-#[contractevent(topics = ["payout", "done"])]
-pub struct PayoutDoneEvent {
+#[contractevent(topics = ["sub", "withdraw"])]
+pub struct SubWithdrawEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub refund: i128,
+}
+
+#[contractevent(topics = ["stake", "legacy"])]
+pub struct LegacyStakeSettleEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["raffle", "entry"])]
+pub struct RaffleEntryEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+}
+
+#[contractevent(topics = ["raffle", "draw"])]
+pub struct RaffleDrawEvent {
+    pub mission_id: u64,
+    pub seed: BytesN<32>,
+    pub winners: Vec<Address>,
+}
+
+#[contractevent(topics = ["sub", "reveal"])]
+pub struct SubRevealEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+}
+
+#[contractevent(topics = ["sub", "forfeit"])]
+pub struct SubForfeitEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["sub", "approved"])]
+pub struct SubApprovedEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["reward", "claim"])]
+pub struct RewardClaimEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["sub", "rejected"])]
+pub struct SubRejectedEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub reason_cid: String,
+}
+
+#[contractevent(topics = ["sub", "unreviewed"])]
+pub struct SubUnreviewedEvent {
     pub mission_id: u64,
     pub hunter: Address,
+    pub owner: Address,
+    pub paid: bool,
+}
+
+#[contractevent(topics = ["fee", "collected"])]
+pub struct FeeCollectedEvent {
+    pub mission_id: u64,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["mission", "fund"])]
+pub struct MissionFundEvent {
+    pub mission_id: u64,
+    pub extra_slots: u32,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["reward", "raise"])]
+pub struct RewardRaiseEvent {
+    pub mission_id: u64,
+    pub reward_amount: i128,
+    pub amount: i128,
 }
 
 #[contractevent(topics = ["mission", "cancel"], data_format = "single-value")]
@@ -31,16 +211,107 @@ pub struct MissionCancelEvent {
     pub mission_id: u64,
 }
 
+#[contractevent(topics = ["slash", "pending"])]
+pub struct SlashPendingEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub amount: i128,
+    pub reason_cid: String,
+}
+
+#[contractevent(topics = ["slash", "appeal"])]
+pub struct SlashAppealEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub evidence_cid: String,
+}
+
+#[contractevent(topics = ["slash", "resolve"])]
+pub struct SlashResolveEvent {
+    pub mission_id: u64,
+    pub hunter: Address,
+    pub status: SlashStatus,
+}
+
+#[contractevent(topics = ["reviewer", "add"])]
+pub struct ReviewerAddEvent {
+    pub mission_id: u64,
+    pub reviewer: Address,
+    pub role: MissionRole,
+}
+
+#[contractevent(topics = ["reviewer", "remove"])]
+pub struct ReviewerRemoveEvent {
+    pub mission_id: u64,
+    pub reviewer: Address,
+}
+
 #[contracttype]
 pub struct Reward {
     pub reward_token: Address,
+    /// Base reward for every slot not covered by `tiers`.
     pub reward_amount: i128,
+    /// Prize tiers in ranking order. Their counts come out of `max_participants`.
+    pub tiers: Vec<RewardTier>,
+    /// Award the prizes by random draw among eligible submissions.
+    pub raffle: bool,
 }
 
 #[contracttype]
 pub struct MinAsset {
-    pub min_asset_token: Option<Address>,
-    pub min_asset_amount: i128,
+    /// Token balances a hunter must hold, combined by `rule`.
+    pub requirements: Vec<AssetRequirement>,
+    pub rule: GateRule,
+    pub reputation: ReputationGate,
+    pub allowlist: Allowlist,
+}
+
+#[contracttype]
+pub struct StakePolicy {
+    pub stake_token: Option<Address>,
+    pub stake_amount: i128,
+}
+
+/// Terms of a new mission, passed to `create_mission` as one argument.
+#[contracttype]
+pub struct MissionParams {
+    pub reward: Reward,
+    pub max_participants: u32,
+    pub min_asset: MinAsset,
+    pub stake_policy: StakePolicy,
+    pub schedule: Schedule,
+}
+
+#[contracttype]
+pub struct Schedule {
+    pub submission_deadline: u64,
+    pub review_deadline: u64,
+    /// Seconds the owner has to review a submission before the hunter may
+    /// call `claim_unreviewed`.
+    pub review_window: u64,
+    /// Pay unreviewed submissions instead of only refunding their stake.
+    pub auto_approve: bool,
+    /// When non-zero, hunters commit to a hash before `submission_deadline`
+    /// and reveal their CID until this time.
+    pub reveal_deadline: u64,
+}
+
+/// Outcome for one hunter in a batch call. `error` holds the `QuidError`
+/// code when that hunter was skipped.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchResult {
+    pub hunter: Address,
+    pub error: Option<u32>,
+}
+
+impl BatchResult {
+    fn from_outcome(hunter: Address, outcome: Result<(), QuidError>) -> Self {
+        BatchResult {
+            hunter,
+            error: outcome.err().map(|err| err as u32),
+        }
+    }
 }
 
 #[contractevent(topics = ["mission", "pause"], data_format = "single-value")]
@@ -48,6 +319,24 @@ pub struct MissionPauseEvent {
     pub mission_id: u64,
 }
 
+#[contractevent(topics = ["mission", "resume"], data_format = "single-value")]
+pub struct MissionResumeEvent {
+    pub mission_id: u64,
+}
+
+#[contractevent(topics = ["mission", "status"])]
+pub struct MissionStatusEvent {
+    pub mission_id: u64,
+    pub from: MissionStatus,
+    pub to: MissionStatus,
+}
+
+#[contractevent(topics = ["mission", "expire"])]
+pub struct MissionExpireEvent {
+    pub mission_id: u64,
+    pub refund_amount: i128,
+}
+
 #[contract]
 pub struct QuidStoreContract;
 
@@ -59,35 +348,99 @@ impl QuidStoreContract {
         owner: Address,
         title: String,
         description_cid: String,
-        reward: Reward,
-        max_participants: u32,
-        min_asset: MinAsset,
+        params: MissionParams,
     ) -> Result<u64, QuidError> {
+        Self::require_not_paused(&env)?;
+
+        let MissionParams {
+            reward,
+            max_participants,
+            min_asset,
+            stake_policy,
+            schedule,
+        } = params;
+
         owner.require_auth();
 
         Self::validate_mission_params(&title, reward.reward_amount)?;
 
+        // Submissions must close in the future and review cannot end before they close
+        if schedule.submission_deadline <= env.ledger().timestamp()
+            || schedule.review_deadline < schedule.submission_deadline
+            || schedule.review_window == 0
+        {
+            return Err(QuidError::InvalidDeadline);
+        }
+        if schedule.reveal_deadline != 0
+            && (schedule.reveal_deadline <= schedule.submission_deadline
+                || schedule.reveal_deadline > schedule.review_deadline)
+        {
+            return Err(QuidError::InvalidDeadline);
+        }
+
         // Validate optional asset gating
-        if min_asset.min_asset_token.is_some() && min_asset.min_asset_amount <= 0 {
+        if min_asset.requirements.len() > MAX_ASSET_REQUIREMENTS
+            || min_asset
+                .requirements
+                .iter()
+                .any(|requirement| requirement.min_amount <= 0)
+        {
             return Err(QuidError::InvalidAmount);
         }
+        if min_asset.reputation.is_set() {
+            Self::get_reputation_contract(env.clone())?;
+        }
+        // An attestation type is only meaningful from a named, trusted issuer
+        if min_asset.reputation.attestation_type.is_some()
+            != min_asset.reputation.attestation_issuer.is_some()
+        {
+            return Err(QuidError::InvalidState);
+        }
+        if let Allowlist::Addresses(addresses) = &min_asset.allowlist {
+            if addresses.is_empty() || addresses.len() > MAX_ALLOWLIST_SIZE {
+                return Err(QuidError::InvalidAllowlist);
+            }
+        }
 
-        let total_needed: i128 = reward
-            .reward_amount
-            .checked_mul(max_participants as i128)
-            .ok_or(QuidError::NegativeReward)?;
+        // A stake token requires a positive stake; no token means zero-stake mode
+        match stake_policy.stake_token {
+            Some(_) if stake_policy.stake_amount <= 0 => return Err(QuidError::InvalidAmount),
+            None if stake_policy.stake_amount != 0 => return Err(QuidError::InvalidAmount),
+            _ => {}
+        }
+        // Unrevealed stakes are forfeited to the treasury
+        if schedule.reveal_deadline != 0 && stake_policy.stake_token.is_some() {
+            Self::get_treasury(env.clone())?;
+        }
 
-        let token_client = token::Client::new(&env, &reward.reward_token);
-        token_client.transfer(&owner, env.current_contract_address(), &total_needed);
+        Self::validate_reward_tiers(&reward.tiers, max_participants)?;
+        if reward.raffle && max_participants > MAX_RAFFLE_WINNERS {
+            return Err(QuidError::BatchTooLarge);
+        }
+
+        Self::require_vetted_token(&env, &reward.reward_token)?;
+        if let Some(stake_token) = &stake_policy.stake_token {
+            Self::require_vetted_token(&env, stake_token)?;
+        }
 
         let mission_id = Self::get_next_mission_id(&env);
 
+        // Missions funded under `FeeMode::OnPayout` lock in the rate for every payout
+        let fee_config = Self::get_fee_config(env.clone());
+        let payout_fee_bps = if fee_config.mode == FeeMode::OnPayout
+            && !Self::is_fee_exempt(env.clone(), owner.clone())
+        {
+            fee_config.fee_bps
+        } else {
+            0
+        };
+
         let created_at = env.ledger().timestamp();
 
-        // let reward = Reward {
-        //     reward_token,
-        //     reward_amount
-        // }
+        let mut tier_paid = Vec::new(&env);
+        for _ in reward.tiers.iter() {
+            tier_paid.push_back(0u32);
+        }
 
         let mission = Mission {
             id: mission_id,
@@ -100,17 +453,45 @@ impl QuidStoreContract {
             participants_count: 0,
             status: MissionStatus::Open,
             created_at,
-            min_asset: min_asset.min_asset_token,
-            min_asset_amount: min_asset.min_asset_amount,
+            asset_requirements: min_asset.requirements,
+            asset_rule: min_asset.rule,
+            stake_token: stake_policy.stake_token,
+            stake_amount: stake_policy.stake_amount,
+            submission_deadline: schedule.submission_deadline,
+            review_deadline: schedule.review_deadline,
+            review_window: schedule.review_window,
+            auto_approve: schedule.auto_approve,
+            payout_fee_bps,
+            reward_tiers: reward.tiers,
+            tier_paid,
+            reputation_gate: min_asset.reputation,
+            allowlist: min_asset.allowlist,
+            reveal_deadline: schedule.reveal_deadline,
+            raffle: reward.raffle,
         };
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Mission(mission_id), &mission);
+        // Escrow covers every tier plus the base slots
+        let total_needed = Self::outstanding_rewards(&mission)?;
+        Self::escrow_in(
+            &env,
+            mission_id,
+            &mission.reward_token,
+            &owner,
+            total_needed,
+        )?;
+        if payout_fee_bps == 0 {
+            Self::collect_funding_fee(
+                &env,
+                mission_id,
+                &owner,
+                &mission.reward_token,
+                total_needed,
+            )?;
+        }
 
-        env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::Mission(mission_id), 5184000, 5184000);
+        Self::save_mission(&env, &mission);
+
+        Self::index_owner_mission(&env, &owner, mission_id);
 
         MissionCreateEvent { mission_id, owner }.publish(&env);
 
@@ -119,285 +500,2422 @@ impl QuidStoreContract {
 
     /// Get mission
     pub fn get_mission(env: Env, mission_id: u64) -> Result<Mission, QuidError> {
-        env.storage()
+        Self::read_mission(&env, mission_id).ok_or(QuidError::MissionNotFound)
+    }
+
+    /// Extend the TTL of a mission and everything stored for it: its owner
+    /// index entry, counters, escrow and liabilities, raffle records, delegated
+    /// roles, the vetting and fee exemption it relies on, and for
+    /// submissions `cursor..cursor + limit` their stakes, owed rewards, held
+    /// slashes and withdrawal markers. At most `MAX_BATCH_SIZE` submissions are visited
+    /// per call; returns how many submissions remain past this page. Anyone
+    /// may pay to keep a long-running campaign from being archived.
+    pub fn bump_mission(
+        env: Env,
+        mission_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<u32, QuidError> {
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+        Self::extend_persistent(&env, &DataKey::Mission(mission_id));
+        Self::extend_instance(&env);
+
+        let mission_keys = [
+            DataKey::RaffleEntrants(mission_id),
+            DataKey::RaffleDraw(mission_id),
+            DataKey::SubmissionCount(mission_id),
+            DataKey::OpenSubmissions(mission_id),
+            DataKey::OwnerMissionCount(mission.owner.clone()),
+            DataKey::Liabilities(mission.reward_token.clone()),
+            DataKey::VettedToken(mission.reward_token.clone()),
+            DataKey::FeeExempt(mission.owner.clone()),
+            DataKey::Reviewers(mission_id),
+            DataKey::OwnerCredited(mission_id),
+        ];
+        for key in mission_keys {
+            Self::extend_if_present(&env, &key);
+        }
+        let reviewers: Vec<Address> = env
+            .storage()
             .persistent()
-            .get(&DataKey::Mission(mission_id))
-            .ok_or(QuidError::MissionNotFound)
+            .get(&DataKey::Reviewers(mission_id))
+            .unwrap_or(Vec::new(&env));
+        for reviewer in reviewers.iter() {
+            Self::extend_if_present(&env, &DataKey::Reviewer(mission_id, reviewer));
+        }
+        Self::extend_if_present(
+            &env,
+            &DataKey::Escrow(mission_id, mission.reward_token.clone()),
+        );
+        if let Some(stake_token) = mission.stake_token {
+            Self::extend_if_present(&env, &DataKey::Liabilities(stake_token.clone()));
+            Self::extend_if_present(&env, &DataKey::VettedToken(stake_token.clone()));
+            Self::extend_if_present(&env, &DataKey::Escrow(mission_id, stake_token));
+        }
+        if let Some(index) = Self::find_owner_mission(&env, &mission.owner, mission_id) {
+            Self::extend_if_present(&env, &DataKey::OwnerMission(mission.owner, index));
+        }
+
+        let count = Self::get_submission_count(env.clone(), mission_id);
+        let end = cursor.saturating_add(limit.min(MAX_BATCH_SIZE)).min(count);
+        for index in cursor..end {
+            let index_key = DataKey::SubmissionIndex(mission_id, index);
+            let Some(hunter) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Address>(&index_key)
+            else {
+                continue;
+            };
+            Self::extend_persistent(&env, &index_key);
+
+            let hunter_keys = [
+                DataKey::Submission(mission_id, hunter.clone()),
+                DataKey::HunterStake(mission_id, hunter.clone()),
+                DataKey::Claimable(mission_id, hunter.clone()),
+                DataKey::Slash(mission_id, hunter.clone()),
+                DataKey::Withdrawn(mission_id, hunter.clone()),
+                DataKey::HunterClaims(hunter),
+            ];
+            for key in hunter_keys {
+                Self::extend_if_present(&env, &key);
+            }
+        }
+
+        Ok(count - end)
     }
 
     /// Submit Feedback
+    /// `proof` is the Merkle proof for missions with an `Allowlist::MerkleRoot`
+    /// and is ignored otherwise.
     pub fn submit_feedback(
         env: Env,
         mission_id: u64,
         hunter: Address,
         ipfs_cid: String,
-        stake_token: Address,
-        stake_amount: i128,
+        proof: Vec<BytesN<32>>,
     ) -> Result<(), QuidError> {
+        Self::require_not_paused(&env)?;
+
         hunter.require_auth();
 
         let mission = Self::get_mission(env.clone(), mission_id)?;
 
-        if mission.status != MissionStatus::Open && mission.status != MissionStatus::Started {
-            return Err(QuidError::MissionNotOpen);
-        }
-        if mission.participants_count >= mission.max_participants {
-            return Err(QuidError::MissionFull);
-        }
-
-        // Check asset gating requirement
-        if let Some(asset_address) = &mission.min_asset {
-            let asset_client = token::Client::new(&env, asset_address);
-            let current_balance = asset_client.balance(&hunter);
-            if current_balance < mission.min_asset_amount {
-                return Err(QuidError::InsufficientAssetBalance);
-            }
-        }
-
-        let key = DataKey::Submission(mission_id, hunter.clone());
-
-        if env.storage().persistent().has(&key) {
-            return Err(QuidError::AlreadySubmitted);
-        }
-
-        if stake_amount <= 0 {
-            return Err(QuidError::InvalidAmount);
+        // Commit-reveal missions only accept commitments
+        if mission.reveal_deadline != 0 {
+            return Err(QuidError::InvalidState);
         }
 
-        let token_client = token::Client::new(&env, &stake_token);
-        token_client.transfer(&hunter, env.current_contract_address(), &stake_amount);
-
-        let stake_key = DataKey::HunterStake(mission_id, hunter.clone());
-        env.storage().persistent().set(&stake_key, &stake_amount);
-        env.storage()
-            .persistent()
-            .extend_ttl(&stake_key, 5184000, 5184000);
+        Self::enter_mission(&env, &mission, &hunter, &proof)?;
 
         let submission = Submission {
             hunter: hunter.clone(),
             ipfs_cid,
             status: SubmissionStatus::Pending,
             submitted_at: env.ledger().timestamp(),
+            commitment: None,
         };
-
-        env.storage().persistent().set(&key, &submission);
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, 5184000, 5184000);
-
-        SubNewEvent { mission_id, hunter }.publish(&env);
+        Self::save_new_submission(&env, mission_id, submission);
 
         Ok(())
     }
 
-    /// Update Submission
-    pub fn update_submission(
+    /// Commit to feedback on a commit-reveal mission.
+    /// `commitment` is `sha256(mission_id || hunter || cid || salt)`, where
+    /// `mission_id` is 8 big-endian bytes, `hunter` is the address's strkey
+    /// (`G...`/`C...`) as ASCII and `cid` is UTF-8. Binding the mission and
+    /// hunter stops others replaying a commitment seen on chain. The hunter
+    /// reveals `cid` and `salt` after submissions close. The stake is taken now.
+    pub fn commit_submission(
         env: Env,
         mission_id: u64,
         hunter: Address,
-        new_ipfs_cid: String,
+        commitment: BytesN<32>,
+        proof: Vec<BytesN<32>>,
     ) -> Result<(), QuidError> {
+        Self::require_not_paused(&env)?;
+
         hunter.require_auth();
 
         let mission = Self::get_mission(env.clone(), mission_id)?;
 
-        if mission.status != MissionStatus::Open {
-            return Err(QuidError::MissionNotOpen);
-        }
-
-        let key = DataKey::Submission(mission_id, hunter.clone());
-
-        if !env.storage().persistent().has(&key) {
-            return Err(QuidError::SubmissionNotFound);
+        if mission.reveal_deadline == 0 {
+            return Err(QuidError::InvalidState);
         }
 
-        let submission: Submission = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .ok_or(QuidError::SubmissionNotFound)?;
-
-        if submission.status == SubmissionStatus::Paid {
-            return Err(QuidError::AlreadyPaid);
-        }
+        Self::enter_mission(&env, &mission, &hunter, &proof)?;
 
-        let updated_submission = Submission {
-            hunter: submission.hunter,
-            ipfs_cid: new_ipfs_cid,
-            status: submission.status,
-            submitted_at: submission.submitted_at,
+        let submission = Submission {
+            hunter: hunter.clone(),
+            ipfs_cid: String::from_str(&env, ""),
+            status: SubmissionStatus::Committed,
+            submitted_at: env.ledger().timestamp(),
+            commitment: Some(commitment),
         };
-
-        env.storage().persistent().set(&key, &updated_submission);
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, 5184000, 5184000);
+        Self::save_new_submission(&env, mission_id, submission);
 
         Ok(())
     }
 
-    /// Payout Participant
-    pub fn payout_participant(env: Env, mission_id: u64, hunter: Address) -> Result<(), QuidError> {
-        let mut mission = Self::get_mission(env.clone(), mission_id)?;
-        mission.owner.require_auth();
-
-        if matches!(
-            mission.status,
-            MissionStatus::Completed | MissionStatus::Cancelled
-        ) {
-            return Err(QuidError::MissionClosed);
-        }
+    /// Reveal a committed submission between the submission and reveal deadlines.
+    pub fn reveal_submission(
+        env: Env,
+        mission_id: u64,
+        hunter: Address,
+        ipfs_cid: String,
+        salt: Bytes,
+    ) -> Result<(), QuidError> {
+        hunter.require_auth();
 
-        let key = DataKey::Submission(mission_id, hunter.clone());
-        let mut submission: Submission = env
-            .storage()
-            .persistent()
-            .get(&key)
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+        let mut submission = Self::read_submission(&env, mission_id, &hunter)
             .ok_or(QuidError::SubmissionNotFound)?;
 
-        if submission.status == SubmissionStatus::Paid {
-            return Err(QuidError::AlreadyPaid);
-        }
-        if submission.status != SubmissionStatus::Pending {
+        if submission.status != SubmissionStatus::Committed {
             return Err(QuidError::NotPending);
         }
 
-        let token_client = token::Client::new(&env, &mission.reward_token);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &hunter,
-            &mission.reward_amount,
-        );
-
-        // Refund the hunter's stake since they won
-        Self::refund_stake(
-            &env,
-            mission_id,
-            hunter.clone(),
-            mission.reward_token.clone(),
-        )?;
-
-        submission.status = SubmissionStatus::Paid;
-        env.storage().persistent().set(&key, &submission);
+        let now = env.ledger().timestamp();
+        if now <= mission.submission_deadline || now > mission.reveal_deadline {
+            return Err(QuidError::OutsideRevealWindow);
+        }
 
-        mission.participants_count += 1;
-        if mission.max_participants > 0 && mission.participants_count >= mission.max_participants {
-            mission.status = MissionStatus::Completed;
+        let mut preimage = Bytes::from_array(&env, &mission_id.to_be_bytes());
+        preimage.append(&hunter.to_string().to_bytes());
+        preimage.append(&ipfs_cid.to_bytes());
+        preimage.append(&salt);
+        let digest: BytesN<32> = env.crypto().sha256(&preimage).into();
+        if submission.commitment != Some(digest) {
+            return Err(QuidError::InvalidReveal);
         }
-        env.storage()
-            .persistent()
-            .set(&DataKey::Mission(mission_id), &mission);
 
-        PayoutDoneEvent { mission_id, hunter }.publish(&env);
+        // The review window starts once the owner can read the feedback
+        submission.ipfs_cid = ipfs_cid;
+        submission.status = SubmissionStatus::Pending;
+        submission.submitted_at = now;
+        Self::save_submission(&env, mission_id, &submission);
+        SubRevealEvent { mission_id, hunter }.publish(&env);
 
         Ok(())
     }
 
-    pub fn cancel_mission(env: Env, mission_id: u64) -> Result<(), QuidError> {
-        let mut mission = Self::get_mission(env.clone(), mission_id)?;
-        mission.owner.require_auth();
+    /// Forfeit the stake of a commitment that was never revealed.
+    /// Anyone may call this once the reveal deadline has passed; the stake
+    /// goes to the treasury.
+    pub fn forfeit_unrevealed(env: Env, mission_id: u64, hunter: Address) -> Result<(), QuidError> {
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+        let mut submission = Self::read_submission(&env, mission_id, &hunter)
+            .ok_or(QuidError::SubmissionNotFound)?;
 
-        if matches!(
-            mission.status,
-            MissionStatus::Cancelled | MissionStatus::Completed
-        ) {
-            return Err(QuidError::MissionClosed);
+        if submission.status != SubmissionStatus::Committed {
+            return Err(QuidError::NotPending);
+        }
+        if env.ledger().timestamp() <= mission.reveal_deadline {
+            return Err(QuidError::DeadlineNotReached);
         }
 
-        let remaining_slots = mission
+        Self::forfeit_commitment(&env, &mission, hunter)?;
+
+        submission.status = SubmissionStatus::Expired;
+        Self::save_submission(&env, mission_id, &submission);
+
+        Ok(())
+    }
+
+    /// Get submission
+    pub fn get_submission(
+        env: Env,
+        mission_id: u64,
+        hunter: Address,
+    ) -> Result<Submission, QuidError> {
+        Self::read_submission(&env, mission_id, &hunter).ok_or(QuidError::SubmissionNotFound)
+    }
+
+    /// Number of submissions ever made to a mission.
+    pub fn get_submission_count(env: Env, mission_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SubmissionCount(mission_id))
+            .unwrap_or(0)
+    }
+
+    /// Number of submissions still awaiting review or reveal.
+    pub fn get_open_submission_count(env: Env, mission_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OpenSubmissions(mission_id))
+            .unwrap_or(0)
+    }
+
+    /// List submissions in the order they were made, starting at index `cursor`
+    /// and scanning at most `min(limit, MAX_PAGE_SIZE)` entries; larger limits
+    /// are clamped. Continue from `cursor + min(limit, MAX_PAGE_SIZE)`.
+    pub fn list_submissions(env: Env, mission_id: u64, cursor: u32, limit: u32) -> Vec<Submission> {
+        let count = Self::get_submission_count(env.clone(), mission_id);
+        let end = cursor.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut submissions = Vec::new(&env);
+        for index in cursor..end {
+            let submission = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Address>(&DataKey::SubmissionIndex(mission_id, index))
+                .and_then(|hunter| Self::read_submission(&env, mission_id, &hunter));
+            if let Some(submission) = submission {
+                submissions.push_back(submission);
+            }
+        }
+        submissions
+    }
+
+    /// List missions with ids after `cursor`, scanning at most
+    /// `min(limit, MAX_PAGE_SIZE)` ids; larger limits are clamped. When
+    /// `status_filter` is set, only matching missions in that window are
+    /// returned, so a page may hold fewer entries than were scanned. Continue
+    /// from `cursor + min(limit, MAX_PAGE_SIZE)`.
+    pub fn list_missions(
+        env: Env,
+        cursor: u64,
+        limit: u32,
+        status_filter: Option<MissionStatus>,
+    ) -> Vec<Mission> {
+        let count = Self::get_mission_count(env.clone());
+        let end = cursor
+            .saturating_add(limit.min(MAX_PAGE_SIZE) as u64)
+            .min(count);
+
+        let mut missions = Vec::new(&env);
+        for mission_id in cursor.saturating_add(1)..=end {
+            if let Ok(mission) = Self::get_mission(env.clone(), mission_id) {
+                if status_filter.is_none_or(|status| status == mission.status) {
+                    missions.push_back(mission);
+                }
+            }
+        }
+        missions
+    }
+
+    /// Number of missions created by `owner`.
+    pub fn get_owner_mission_count(env: Env, owner: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OwnerMissionCount(owner))
+            .unwrap_or(0)
+    }
+
+    /// List an owner's missions in creation order, starting at index `cursor`
+    /// and scanning at most `min(limit, MAX_PAGE_SIZE)` entries. Filtering and
+    /// paging work as in `list_missions`.
+    pub fn list_missions_by_owner(
+        env: Env,
+        owner: Address,
+        cursor: u32,
+        limit: u32,
+        status_filter: Option<MissionStatus>,
+    ) -> Vec<Mission> {
+        let count = Self::get_owner_mission_count(env.clone(), owner.clone());
+        let end = cursor.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+        let mut missions = Vec::new(&env);
+        for index in cursor..end {
+            let mission = env
+                .storage()
+                .persistent()
+                .get::<DataKey, u64>(&DataKey::OwnerMission(owner.clone(), index))
+                .and_then(|mission_id| Self::get_mission(env.clone(), mission_id).ok());
+            if let Some(mission) = mission {
+                if status_filter.is_none_or(|status| status == mission.status) {
+                    missions.push_back(mission);
+                }
+            }
+        }
+        missions
+    }
+
+    /// Update Submission
+    pub fn update_submission(
+        env: Env,
+        mission_id: u64,
+        hunter: Address,
+        new_ipfs_cid: String,
+    ) -> Result<(), QuidError> {
+        hunter.require_auth();
+
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+
+        if mission.status != MissionStatus::Open {
+            return Err(QuidError::MissionNotOpen);
+        }
+        // Revealed CIDs are final, otherwise hunters could copy them
+        if mission.reveal_deadline != 0 {
+            return Err(QuidError::InvalidState);
+        }
+        if env.ledger().timestamp() > mission.submission_deadline {
+            return Err(QuidError::DeadlinePassed);
+        }
+
+        let key = DataKey::Submission(mission_id, hunter.clone());
+
+        if !env.storage().persistent().has(&key) {
+            return Err(QuidError::SubmissionNotFound);
+        }
+
+        let submission = Self::read_submission(&env, mission_id, &hunter)
+            .ok_or(QuidError::SubmissionNotFound)?;
+
+        // Approved, rejected and slashed CIDs are what was judged; keep them
+        if submission.status != SubmissionStatus::Pending {
+            return Err(QuidError::NotPending);
+        }
+        // Raffle entrants stay pending until the draw, but were judged eligible
+        if mission.raffle && Self::get_raffle_entrants(env.clone(), mission_id).contains(&hunter) {
+            return Err(QuidError::InvalidState);
+        }
+
+        let updated_submission = Submission {
+            hunter: submission.hunter,
+            ipfs_cid: new_ipfs_cid,
+            status: submission.status,
+            submitted_at: submission.submitted_at,
+            commitment: submission.commitment,
+        };
+
+        Self::save_submission(&env, mission_id, &updated_submission);
+        Ok(())
+    }
+
+    /// Withdraw a submission made by mistake.
+    /// Allowed while the submission awaits review and submissions are still
+    /// open, including while the owner has paused the mission. The stake is
+    /// refunded minus the protocol withdrawal fee.
+    /// Raffle entrants lose their place in the draw.
+    pub fn withdraw_submission(
+        env: Env,
+        mission_id: u64,
+        hunter: Address,
+    ) -> Result<(), QuidError> {
+        hunter.require_auth();
+
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+
+        // Pausing must not lock stakes; the deadline below marks the start of review
+        if !matches!(
+            mission.status,
+            MissionStatus::Open | MissionStatus::Started | MissionStatus::Paused
+        ) {
+            return Err(QuidError::MissionNotOpen);
+        }
+        if env.ledger().timestamp() > mission.submission_deadline {
+            return Err(QuidError::DeadlinePassed);
+        }
+
+        let key = DataKey::Submission(mission_id, hunter.clone());
+        let submission = Self::read_submission(&env, mission_id, &hunter)
+            .ok_or(QuidError::SubmissionNotFound)?;
+        if !matches!(
+            submission.status,
+            SubmissionStatus::Pending | SubmissionStatus::Committed
+        ) {
+            return Err(QuidError::NotPending);
+        }
+
+        let stake_key = DataKey::HunterStake(mission_id, hunter.clone());
+        let mut refund = 0;
+        if let (Some(stake_token), Some(amount)) = (
+            &mission.stake_token,
+            env.storage().persistent().get::<DataKey, i128>(&stake_key),
+        ) {
+            let config = Self::get_fee_config(env.clone());
+            let fee = Self::compute_fee(&config, amount, config.withdrawal_fee_bps);
+
+            if fee > 0 {
+                let treasury = Self::get_treasury(env.clone())?;
+                Self::release(&env, mission_id, stake_token, &treasury, fee)?;
+                FeeCollectedEvent {
+                    mission_id,
+                    token: stake_token.clone(),
+                    amount: fee,
+                }
+                .publish(&env);
+            }
+
+            refund = amount - fee;
+            Self::release(&env, mission_id, stake_token, &hunter, refund)?;
+            env.storage().persistent().remove(&stake_key);
+        }
+
+        env.storage().persistent().remove(&key);
+        Self::count_open_submission(&env, mission_id, false);
+        Self::persist(&env, &DataKey::Withdrawn(mission_id, hunter.clone()), &true);
+
+        // A resubmission is a new entry and must be marked eligible again
+        if mission.raffle {
+            let entrants_key = DataKey::RaffleEntrants(mission_id);
+            let mut entrants = Self::get_raffle_entrants(env.clone(), mission_id);
+            if let Some(index) = entrants.first_index_of(&hunter) {
+                entrants.remove(index);
+                Self::persist(&env, &entrants_key, &entrants);
+            }
+        }
+
+        SubWithdrawEvent {
+            mission_id,
+            hunter,
+            refund,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Payout Participant
+    /// `tier` indexes the mission's prize tiers; `None` pays the base reward.
+    /// Equivalent to `approve_submission`: the reward and stake become
+    /// claimable by the hunter rather than being transferred.
+    pub fn payout_participant(
+        env: Env,
+        mission_id: u64,
+        caller: Address,
+        hunter: Address,
+        tier: Option<u32>,
+    ) -> Result<(), QuidError> {
+        Self::approve_submission(env, mission_id, caller, hunter, tier)
+    }
+
+    /// Approve several hunters in one call; rewards become claimable.
+    /// Each hunter is validated independently; failures are reported in the
+    /// result instead of aborting the batch. No tokens move to the hunters, so
+    /// an account that cannot receive (e.g. a frozen trustline) only affects its
+    /// own later claim. The mission is written once and every hunter is paid
+    /// from the same `tier`. At most `MAX_BATCH_SIZE` hunters per call.
+    pub fn payout_batch(
+        env: Env,
+        mission_id: u64,
+        caller: Address,
+        hunters: Vec<Address>,
+        tier: Option<u32>,
+    ) -> Result<Vec<BatchResult>, QuidError> {
+        Self::require_not_paused(&env)?;
+
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        Self::require_role(&env, &mission, &caller, MissionRole::Reviewer)?;
+
+        // Raffle prizes are only awarded by `draw_winners`
+        if mission.raffle {
+            return Err(QuidError::InvalidState);
+        }
+
+        if hunters.len() > MAX_BATCH_SIZE {
+            return Err(QuidError::BatchTooLarge);
+        }
+
+        let mut results = Vec::new(&env);
+        for hunter in hunters.iter() {
+            let outcome = if mission.status.is_closed() {
+                Err(QuidError::MissionClosed)
+            } else {
+                Self::get_pending_submission(&env, mission_id, &hunter).and_then(
+                    |mut submission| {
+                        Self::approve(&env, &mut mission, hunter.clone(), &mut submission, tier)
+                    },
+                )
+            };
+            results.push_back(BatchResult::from_outcome(hunter, outcome));
+        }
+
+        Self::save_mission(&env, &mission);
+
+        Ok(results)
+    }
+
+    /// Resolve a submission the owner never reviewed.
+    /// Once the mission's review window has elapsed since submission, the
+    /// hunter recovers their stake. Missions created with `auto_approve`
+    /// instead approve the base reward while base slots remain, leaving the
+    /// reward and stake to `claim_reward`.
+    pub fn claim_unreviewed(env: Env, mission_id: u64, hunter: Address) -> Result<(), QuidError> {
+        hunter.require_auth();
+
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        let mut submission = Self::get_pending_submission(&env, mission_id, &hunter)?;
+
+        let review_ends_at = submission
+            .submitted_at
+            .saturating_add(mission.review_window);
+        if env.ledger().timestamp() <= review_ends_at {
+            return Err(QuidError::ReviewWindowOpen);
+        }
+
+        let can_pay = mission.auto_approve
+            && !mission.raffle
+            && !mission.status.is_closed()
+            && Self::base_slots_remaining(&mission) > 0;
+
+        if can_pay {
+            Self::require_not_paused(&env)?;
+            Self::approve(&env, &mut mission, hunter.clone(), &mut submission, None)?;
+            Self::save_mission(&env, &mission);
+        } else {
+            Self::refund_stake(&env, &mission, hunter.clone())?;
+            submission.status = SubmissionStatus::Expired;
+            Self::save_submission(&env, mission_id, &submission);
+        }
+
+        SubUnreviewedEvent {
+            mission_id,
+            hunter,
+            owner: mission.owner,
+            paid: can_pay,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Add base reward slots to a live mission.
+    /// The owner escrows `extra_slots * reward_amount` up front.
+    pub fn fund_mission(env: Env, mission_id: u64, extra_slots: u32) -> Result<(), QuidError> {
+        Self::require_not_paused(&env)?;
+
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        mission.owner.require_auth();
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+        if extra_slots == 0 {
+            return Err(QuidError::InvalidAmount);
+        }
+
+        let amount: i128 = mission
+            .reward_amount
+            .checked_mul(extra_slots as i128)
+            .ok_or(QuidError::NegativeReward)?;
+        let max_participants = mission
+            .max_participants
+            .checked_add(extra_slots)
+            .ok_or(QuidError::InvalidAmount)?;
+        if mission.raffle && max_participants > MAX_RAFFLE_WINNERS {
+            return Err(QuidError::BatchTooLarge);
+        }
+
+        Self::escrow_in(
+            &env,
+            mission_id,
+            &mission.reward_token,
+            &mission.owner,
+            amount,
+        )?;
+        if mission.payout_fee_bps == 0 {
+            Self::collect_funding_fee(
+                &env,
+                mission_id,
+                &mission.owner,
+                &mission.reward_token,
+                amount,
+            )?;
+        }
+
+        mission.max_participants = max_participants;
+        Self::save_mission(&env, &mission);
+
+        MissionFundEvent {
+            mission_id,
+            extra_slots,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Increase the base reward for every base slot not yet paid out.
+    /// The owner escrows the difference for the remaining slots; prize tiers
+    /// are unchanged.
+    pub fn raise_reward(env: Env, mission_id: u64, new_amount: i128) -> Result<(), QuidError> {
+        Self::require_not_paused(&env)?;
+
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        mission.owner.require_auth();
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+        if new_amount <= mission.reward_amount {
+            return Err(QuidError::InvalidAmount);
+        }
+
+        let remaining_slots = Self::base_slots_remaining(&mission);
+        let amount: i128 = (new_amount - mission.reward_amount)
+            .checked_mul(remaining_slots as i128)
+            .ok_or(QuidError::NegativeReward)?;
+
+        if amount > 0 {
+            Self::escrow_in(
+                &env,
+                mission_id,
+                &mission.reward_token,
+                &mission.owner,
+                amount,
+            )?;
+            if mission.payout_fee_bps == 0 {
+                Self::collect_funding_fee(
+                    &env,
+                    mission_id,
+                    &mission.owner,
+                    &mission.reward_token,
+                    amount,
+                )?;
+            }
+        }
+
+        mission.reward_amount = new_amount;
+        Self::save_mission(&env, &mission);
+
+        RewardRaiseEvent {
+            mission_id,
+            reward_amount: new_amount,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Approve a pending submission without transferring the reward.
+    /// The reward, net of any payout fee, and the hunter's stake are owed to
+    /// the hunter until they call `claim_reward` or `claim_all`.
+    pub fn approve_submission(
+        env: Env,
+        mission_id: u64,
+        caller: Address,
+        hunter: Address,
+        tier: Option<u32>,
+    ) -> Result<(), QuidError> {
+        Self::require_not_paused(&env)?;
+
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        Self::require_role(&env, &mission, &caller, MissionRole::Reviewer)?;
+
+        // Raffle prizes are only awarded by `draw_winners`
+        if mission.raffle {
+            return Err(QuidError::InvalidState);
+        }
+
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+
+        let mut submission = Self::get_pending_submission(&env, mission_id, &hunter)?;
+        Self::approve(&env, &mut mission, hunter, &mut submission, tier)?;
+        Self::save_mission(&env, &mission);
+
+        Ok(())
+    }
+
+    /// Enter pending submissions into a raffle mission's draw.
+    /// Each hunter is validated independently, as in `payout_batch`.
+    pub fn mark_eligible(
+        env: Env,
+        mission_id: u64,
+        caller: Address,
+        hunters: Vec<Address>,
+    ) -> Result<Vec<BatchResult>, QuidError> {
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+        Self::require_role(&env, &mission, &caller, MissionRole::Reviewer)?;
+
+        if !mission.raffle {
+            return Err(QuidError::InvalidState);
+        }
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+        if hunters.len() > MAX_PAGE_SIZE {
+            return Err(QuidError::BatchTooLarge);
+        }
+
+        let mut entrants = Self::get_raffle_entrants(env.clone(), mission_id);
+        let mut results = Vec::new(&env);
+        for hunter in hunters.iter() {
+            let outcome = Self::get_pending_submission(&env, mission_id, &hunter).and_then(|_| {
+                if entrants.contains(&hunter) {
+                    Err(QuidError::InvalidState)
+                } else if entrants.len() >= MAX_RAFFLE_ENTRANTS {
+                    Err(QuidError::BatchTooLarge)
+                } else {
+                    entrants.push_back(hunter.clone());
+                    RaffleEntryEvent {
+                        mission_id,
+                        hunter: hunter.clone(),
+                    }
+                    .publish(&env);
+                    Ok(())
+                }
+            });
+            results.push_back(BatchResult::from_outcome(hunter, outcome));
+        }
+
+        let key = DataKey::RaffleEntrants(mission_id);
+        Self::persist(&env, &key, &entrants);
+        Ok(results)
+    }
+
+    /// Hunters entered into a raffle mission's draw.
+    pub fn get_raffle_entrants(env: Env, mission_id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RaffleEntrants(mission_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Draw a raffle mission's winners once submissions have closed.
+    /// Winners are approved in draw order, filling prize tiers before base
+    /// slots, and claim through `claim_reward`. Unawarded prizes go back to the
+    /// owner and the mission completes; every other pending stake is then
+    /// returned through `refund_pending`.
+    pub fn draw_winners(
+        env: Env,
+        mission_id: u64,
+        caller: Address,
+    ) -> Result<RaffleDraw, QuidError> {
+        Self::require_not_paused(&env)?;
+
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        Self::require_role(&env, &mission, &caller, MissionRole::Manager)?;
+
+        if !mission.raffle {
+            return Err(QuidError::InvalidState);
+        }
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+        if env.ledger().timestamp() <= mission.submission_deadline {
+            return Err(QuidError::DeadlineNotReached);
+        }
+
+        // Record the seed so the shuffle can be replayed
+        let entrants = Self::get_raffle_entrants(env.clone(), mission_id);
+        let seed: BytesN<32> = env.prng().gen();
+        env.prng().seed(seed.clone().into());
+        let mut order = entrants.clone();
+        env.prng().shuffle(&mut order);
+
+        let mut winners = Vec::new(&env);
+        for hunter in order.iter() {
+            if mission.status == MissionStatus::Completed {
+                break;
+            }
+            // Skip entrants rejected or withdrawn since they were marked
+            let Ok(mut submission) = Self::get_pending_submission(&env, mission_id, &hunter) else {
+                continue;
+            };
+            let tier = Self::next_open_tier(&mission);
+            Self::approve(&env, &mut mission, hunter.clone(), &mut submission, tier)?;
+            winners.push_back(hunter);
+        }
+
+        Self::refund_unused_rewards(&env, &mission)?;
+        if mission.status != MissionStatus::Completed {
+            Self::set_status(&env, &mut mission, MissionStatus::Completed);
+        }
+        Self::save_mission(&env, &mission);
+
+        let draw = RaffleDraw {
+            seed: seed.clone(),
+            entrants,
+            winners: winners.clone(),
+            drawn_at: env.ledger().timestamp(),
+        };
+        let key = DataKey::RaffleDraw(mission_id);
+        Self::persist(&env, &key, &draw);
+        RaffleDrawEvent {
+            mission_id,
+            seed,
+            winners,
+        }
+        .publish(&env);
+
+        Ok(draw)
+    }
+
+    pub fn get_raffle_draw(env: Env, mission_id: u64) -> Option<RaffleDraw> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RaffleDraw(mission_id))
+    }
+
+    /// Withdraw the reward owed to `hunter` for one mission.
+    pub fn claim_reward(env: Env, mission_id: u64, hunter: Address) -> Result<i128, QuidError> {
+        Self::require_not_paused(&env)?;
+
+        hunter.require_auth();
+
+        let amount = Self::claim(&env, mission_id, &hunter)?;
+
+        let claims_key = DataKey::HunterClaims(hunter.clone());
+        let mut claims: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&claims_key)
+            .unwrap_or(Vec::new(&env));
+        if let Some(index) = claims.first_index_of(mission_id) {
+            claims.remove(index);
+        }
+        if claims.is_empty() {
+            env.storage().persistent().remove(&claims_key);
+        } else {
+            Self::persist(&env, &claims_key, &claims);
+        }
+
+        Ok(amount)
+    }
+
+    /// Withdraw rewards owed to `hunter` across missions.
+    /// At most `MAX_PAGE_SIZE` claims are settled per call; the settled
+    /// balances are returned.
+    pub fn claim_all(env: Env, hunter: Address) -> Result<Vec<ClaimableBalance>, QuidError> {
+        Self::require_not_paused(&env)?;
+
+        hunter.require_auth();
+
+        let claims_key = DataKey::HunterClaims(hunter.clone());
+        let claims: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&claims_key)
+            .ok_or(QuidError::NothingToClaim)?;
+
+        let mut claimed = Vec::new(&env);
+        let mut remaining = Vec::new(&env);
+        for (index, mission_id) in claims.iter().enumerate() {
+            if index as u32 >= MAX_PAGE_SIZE {
+                remaining.push_back(mission_id);
+                continue;
+            }
+            if let Some(claimable) = Self::get_claimable(env.clone(), mission_id, hunter.clone()) {
+                Self::claim(&env, mission_id, &hunter)?;
+                claimed.push_back(claimable);
+            }
+        }
+
+        if remaining.is_empty() {
+            env.storage().persistent().remove(&claims_key);
+        } else {
+            Self::persist(&env, &claims_key, &remaining);
+        }
+
+        Ok(claimed)
+    }
+
+    /// Reward owed to `hunter` for a mission, if any.
+    pub fn get_claimable(env: Env, mission_id: u64, hunter: Address) -> Option<ClaimableBalance> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Claimable(mission_id, hunter))
+    }
+
+    /// Every reward currently owed to `hunter`, in approval order.
+    pub fn list_claimable(env: Env, hunter: Address) -> Vec<ClaimableBalance> {
+        let claims: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::HunterClaims(hunter.clone()))
+            .unwrap_or(Vec::new(&env));
+
+        let mut balances = Vec::new(&env);
+        for mission_id in claims.iter() {
+            if let Some(claimable) = Self::get_claimable(env.clone(), mission_id, hunter.clone()) {
+                balances.push_back(claimable);
+            }
+        }
+        balances
+    }
+
+    /// Reject a pending submission and refund the hunter's stake.
+    /// Requires the reviewer role. Rejection does not consume a
+    /// reward slot, so `participants_count` is left untouched.
+    pub fn reject_submission(
+        env: Env,
+        mission_id: u64,
+        caller: Address,
+        hunter: Address,
+        reason_cid: String,
+    ) -> Result<(), QuidError> {
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+        Self::require_role(&env, &mission, &caller, MissionRole::Reviewer)?;
+
+        let mut submission = Self::get_pending_submission(&env, mission_id, &hunter)?;
+
+        // Honest loser: return the stake in full
+        Self::refund_stake(&env, &mission, hunter.clone())?;
+
+        submission.status = SubmissionStatus::Rejected;
+        Self::save_submission(&env, mission_id, &submission);
+
+        SubRejectedEvent {
+            mission_id,
+            hunter,
+            reason_cid,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Grant `reviewer` a role on the mission, replacing any existing one.
+    /// Requires the owner role. At most `MAX_REVIEWERS` accounts hold a role.
+    pub fn add_reviewer(
+        env: Env,
+        mission_id: u64,
+        caller: Address,
+        reviewer: Address,
+        role: MissionRole,
+    ) -> Result<(), QuidError> {
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+        Self::require_role(&env, &mission, &caller, MissionRole::Owner)?;
+
+        if reviewer == mission.owner {
+            return Err(QuidError::InvalidState);
+        }
+
+        let list_key = DataKey::Reviewers(mission_id);
+        let mut reviewers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&list_key)
+            .unwrap_or(Vec::new(&env));
+        if !reviewers.contains(&reviewer) {
+            if reviewers.len() >= MAX_REVIEWERS {
+                return Err(QuidError::BatchTooLarge);
+            }
+            reviewers.push_back(reviewer.clone());
+            Self::persist(&env, &list_key, &reviewers);
+        }
+
+        let key = DataKey::Reviewer(mission_id, reviewer.clone());
+        Self::persist(&env, &key, &role);
+        ReviewerAddEvent {
+            mission_id,
+            reviewer,
+            role,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Revoke a delegated role. Requires the owner role.
+    pub fn remove_reviewer(
+        env: Env,
+        mission_id: u64,
+        caller: Address,
+        reviewer: Address,
+    ) -> Result<(), QuidError> {
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+        Self::require_role(&env, &mission, &caller, MissionRole::Owner)?;
+
+        let key = DataKey::Reviewer(mission_id, reviewer.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(QuidError::ReviewerNotFound);
+        }
+        env.storage().persistent().remove(&key);
+
+        let list_key = DataKey::Reviewers(mission_id);
+        let mut reviewers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&list_key)
+            .unwrap_or(Vec::new(&env));
+        if let Some(index) = reviewers.first_index_of(&reviewer) {
+            reviewers.remove(index);
+            Self::persist(&env, &list_key, &reviewers);
+        }
+
+        ReviewerRemoveEvent {
+            mission_id,
+            reviewer,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Role held by `account` on the mission. The owner holds `MissionRole::Owner`.
+    pub fn get_role(env: Env, mission_id: u64, account: Address) -> Option<MissionRole> {
+        let mission = Self::get_mission(env.clone(), mission_id).ok()?;
+        if account == mission.owner {
+            return Some(MissionRole::Owner);
+        }
+        env.storage()
+            .persistent()
+            .get(&DataKey::Reviewer(mission_id, account))
+    }
+
+    pub fn cancel_mission(env: Env, mission_id: u64, caller: Address) -> Result<(), QuidError> {
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        Self::require_role(&env, &mission, &caller, MissionRole::Owner)?;
+        Self::cancel(&env, &mut mission)
+    }
+
+    /// Close out a mission whose review deadline has passed.
+    /// Anyone may invoke this: unused reward slots go back to the owner, and
+    /// pending stakes are then returned through `refund_pending`.
+    pub fn expire_mission(env: Env, mission_id: u64) -> Result<(), QuidError> {
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+
+        // Completed missions have already returned their unused escrow
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+        if env.ledger().timestamp() <= mission.review_deadline {
+            return Err(QuidError::DeadlineNotReached);
+        }
+
+        let refund_amount = Self::refund_unused_rewards(&env, &mission)?;
+
+        Self::set_status(&env, &mut mission, MissionStatus::Expired);
+        Self::save_mission(&env, &mission);
+
+        MissionExpireEvent {
+            mission_id,
+            refund_amount,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Resolve the open submissions of a closed mission, scanning at most
+    /// `limit` submissions from index `cursor`. Pending stakes go back to
+    /// their hunters and commitments left unrevealed past the reveal deadline
+    /// are forfeited. Anyone may invoke this once a mission is cancelled,
+    /// expired or completed. Returns how many submissions are still open.
+    pub fn refund_pending(
+        env: Env,
+        mission_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<u32, QuidError> {
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+        if !mission.status.is_closed() {
+            return Err(QuidError::InvalidState);
+        }
+
+        let count = Self::get_submission_count(env.clone(), mission_id);
+        let end = cursor.saturating_add(limit.min(MAX_BATCH_SIZE)).min(count);
+        for index in cursor..end {
+            let Some(hunter) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Address>(&DataKey::SubmissionIndex(mission_id, index))
+            else {
+                continue;
+            };
+
+            let Some(mut submission) = Self::read_submission(&env, mission_id, &hunter) else {
+                continue;
+            };
+            match submission.status {
+                SubmissionStatus::Committed
+                    if env.ledger().timestamp() > mission.reveal_deadline =>
+                {
+                    Self::forfeit_commitment(&env, &mission, hunter)?;
+                }
+                SubmissionStatus::Pending | SubmissionStatus::Committed => {
+                    Self::refund_stake(&env, &mission, hunter)?;
+                }
+                _ => continue,
+            }
+
+            submission.status = SubmissionStatus::Expired;
+            Self::save_submission(&env, mission_id, &submission);
+        }
+
+        Ok(Self::get_open_submission_count(env, mission_id))
+    }
+
+    pub fn pause_mission(env: Env, id: u64, caller: Address) -> Result<(), QuidError> {
+        let mut mission = Self::get_mission(env.clone(), id)?;
+        Self::require_role(&env, &mission, &caller, MissionRole::Manager)?;
+
+        Self::check_transition(mission.status, MissionStatus::Paused)?;
+        Self::set_status(&env, &mut mission, MissionStatus::Paused);
+        Self::save_mission(&env, &mission);
+
+        MissionPauseEvent { mission_id: id }.publish(&env);
+        Ok(())
+    }
+
+    /// Reopen a paused mission for submissions.
+    pub fn resume_mission(env: Env, id: u64, caller: Address) -> Result<(), QuidError> {
+        let mut mission = Self::get_mission(env.clone(), id)?;
+        Self::require_role(&env, &mission, &caller, MissionRole::Manager)?;
+
+        if mission.status != MissionStatus::Paused {
+            return Err(QuidError::InvalidTransition);
+        }
+        Self::set_status(&env, &mut mission, MissionStatus::Open);
+        Self::save_mission(&env, &mission);
+
+        MissionResumeEvent { mission_id: id }.publish(&env);
+        Ok(())
+    }
+
+    /// Move a mission along its lifecycle.
+    /// Cancelling behaves like `cancel_mission`; completing requires every
+    /// submission to be resolved and returns unused reward slots to the owner.
+    pub fn update_mission_status(
+        env: Env,
+        mission_id: u64,
+        caller: Address,
+        new_status: MissionStatus,
+    ) -> Result<(), QuidError> {
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        let role = if new_status == MissionStatus::Cancelled {
+            MissionRole::Owner
+        } else {
+            MissionRole::Manager
+        };
+        Self::require_role(&env, &mission, &caller, role)?;
+
+        Self::check_transition(mission.status, new_status)?;
+
+        match new_status {
+            MissionStatus::Cancelled => return Self::cancel(&env, &mut mission),
+            MissionStatus::Completed => {
+                if Self::has_pending_submissions(&env, mission_id) {
+                    return Err(QuidError::PendingSubmissions);
+                }
+                Self::refund_unused_rewards(&env, &mission)?;
+            }
+            _ => {}
+        }
+
+        Self::set_status(&env, &mut mission, new_status);
+        Self::save_mission(&env, &mission);
+        Ok(())
+    }
+
+    /// Slash a hunter's stake for spam submissions. Sealed commitments cannot
+    /// be slashed until revealed. Requires the manager role. The stake is held for the
+    /// dispute window so the hunter can `appeal_slash`.
+    pub fn slash_hunter_stake(
+        env: Env,
+        mission_id: u64,
+        caller: Address,
+        hunter: Address,
+        reason_cid: String,
+    ) -> Result<(), QuidError> {
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+        Self::require_role(&env, &mission, &caller, MissionRole::Manager)?;
+        Self::slash_stake(&env, &mission, hunter, &reason_cid)
+    }
+
+    /// Slash several hunters' stakes in one call.
+    /// Failures are reported per hunter instead of aborting the batch.
+    /// At most `MAX_BATCH_SIZE` hunters per call.
+    pub fn slash_batch(
+        env: Env,
+        mission_id: u64,
+        caller: Address,
+        hunters: Vec<Address>,
+        reason_cid: String,
+    ) -> Result<Vec<BatchResult>, QuidError> {
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+        Self::require_role(&env, &mission, &caller, MissionRole::Manager)?;
+
+        if hunters.len() > MAX_BATCH_SIZE {
+            return Err(QuidError::BatchTooLarge);
+        }
+
+        let mut results = Vec::new(&env);
+        for hunter in hunters.iter() {
+            let outcome = Self::slash_stake(&env, &mission, hunter.clone(), &reason_cid);
+            results.push_back(BatchResult::from_outcome(hunter, outcome));
+        }
+
+        Ok(results)
+    }
+
+    /// Contest a slash while the dispute window is open.
+    /// The stake stays in the contract until the arbiter resolves the appeal.
+    pub fn appeal_slash(
+        env: Env,
+        mission_id: u64,
+        hunter: Address,
+        evidence_cid: String,
+    ) -> Result<(), QuidError> {
+        hunter.require_auth();
+
+        let key = DataKey::Slash(mission_id, hunter.clone());
+        let mut record = Self::get_slash(env.clone(), mission_id, hunter.clone())?;
+
+        if record.status != SlashStatus::Pending {
+            return Err(QuidError::InvalidState);
+        }
+        if env.ledger().timestamp() > record.slashed_at.saturating_add(DISPUTE_WINDOW) {
+            return Err(QuidError::DisputeWindowClosed);
+        }
+
+        record.evidence_cid = Some(evidence_cid.clone());
+        record.status = SlashStatus::Appealed;
+        Self::persist(&env, &key, &record);
+
+        SlashAppealEvent {
+            mission_id,
+            hunter,
+            evidence_cid,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Decide an appealed slash. `uphold` sends the stake to the treasury,
+    /// otherwise it is returned to the hunter. Only the arbiter may invoke
+    /// this, or the treasury when no arbiter is appointed.
+    pub fn resolve_appeal(
+        env: Env,
+        mission_id: u64,
+        hunter: Address,
+        uphold: bool,
+    ) -> Result<(), QuidError> {
+        let treasury = Self::get_treasury(env.clone())?;
+        Self::get_arbiter(env.clone())
+            .unwrap_or(treasury.clone())
+            .require_auth();
+
+        let record = Self::get_slash(env.clone(), mission_id, hunter.clone())?;
+        if record.status != SlashStatus::Appealed {
+            return Err(QuidError::InvalidState);
+        }
+
+        let status = if uphold {
+            SlashStatus::Upheld
+        } else {
+            SlashStatus::Overturned
+        };
+        Self::settle_slash(&env, mission_id, hunter, record, status, &treasury)
+    }
+
+    /// Send an unappealed slash to the treasury once the dispute window has
+    /// elapsed. Anyone may invoke this.
+    pub fn finalize_slash(env: Env, mission_id: u64, hunter: Address) -> Result<(), QuidError> {
+        let record = Self::get_slash(env.clone(), mission_id, hunter.clone())?;
+
+        if record.status != SlashStatus::Pending {
+            return Err(QuidError::InvalidState);
+        }
+        if env.ledger().timestamp() <= record.slashed_at.saturating_add(DISPUTE_WINDOW) {
+            return Err(QuidError::DisputeWindowOpen);
+        }
+
+        let treasury = Self::get_treasury(env.clone())?;
+        Self::settle_slash(
+            &env,
+            mission_id,
+            hunter,
+            record,
+            SlashStatus::Upheld,
+            &treasury,
+        )
+    }
+
+    pub fn get_slash(env: Env, mission_id: u64, hunter: Address) -> Result<SlashRecord, QuidError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Slash(mission_id, hunter))
+            .ok_or(QuidError::SlashNotFound)
+    }
+
+    pub fn get_mission_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MissionCount)
+            .unwrap_or(0)
+    }
+
+    pub fn mission_exists(env: Env, mission_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Mission(mission_id))
+    }
+
+    fn validate_mission_params(_title: &String, reward_amount: i128) -> Result<(), QuidError> {
+        if reward_amount <= 0 {
+            return Err(QuidError::NegativeReward);
+        }
+        Ok(())
+    }
+
+    /// Require `caller` to authorize and hold at least `role` on the mission.
+    fn require_role(
+        env: &Env,
+        mission: &Mission,
+        caller: &Address,
+        role: MissionRole,
+    ) -> Result<(), QuidError> {
+        caller.require_auth();
+
+        if *caller == mission.owner {
+            return Ok(());
+        }
+        match env
+            .storage()
+            .persistent()
+            .get::<DataKey, MissionRole>(&DataKey::Reviewer(mission.id, caller.clone()))
+        {
+            Some(granted) if granted >= role => Ok(()),
+            _ => Err(QuidError::NotAuthorized),
+        }
+    }
+
+    /// Checks shared by direct and committed submissions; escrows the stake.
+    fn enter_mission(
+        env: &Env,
+        mission: &Mission,
+        hunter: &Address,
+        proof: &Vec<BytesN<32>>,
+    ) -> Result<(), QuidError> {
+        if mission.status != MissionStatus::Open && mission.status != MissionStatus::Started {
+            return Err(QuidError::MissionNotOpen);
+        }
+        if env.ledger().timestamp() > mission.submission_deadline {
+            return Err(QuidError::DeadlinePassed);
+        }
+        if mission.participants_count >= mission.max_participants {
+            return Err(QuidError::MissionFull);
+        }
+
+        Self::check_allowlist(env, mission, hunter, proof)?;
+
+        // Check asset gating requirements
+        Self::check_asset_requirements(env, mission, hunter)?;
+        if mission.reputation_gate.is_set() {
+            Self::check_reputation(env, &mission.reputation_gate, hunter)?;
+        }
+
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Submission(mission.id, hunter.clone()))
+        {
+            return Err(QuidError::AlreadySubmitted);
+        }
+
+        // The stake is dictated by the mission, not chosen by the hunter
+        if let Some(stake_token) = &mission.stake_token {
+            Self::escrow_in(env, mission.id, stake_token, hunter, mission.stake_amount)?;
+
+            let stake_key = DataKey::HunterStake(mission.id, hunter.clone());
+            Self::persist(env, &stake_key, &mission.stake_amount);
+        }
+
+        Ok(())
+    }
+
+    fn save_new_submission(env: &Env, mission_id: u64, submission: Submission) {
+        let hunter = submission.hunter.clone();
+
+        Self::save_submission(env, mission_id, &submission);
+        // A hunter resubmitting after a withdrawal keeps their original index slot
+        let withdrawn_key = DataKey::Withdrawn(mission_id, hunter.clone());
+        if env.storage().persistent().has(&withdrawn_key) {
+            env.storage().persistent().remove(&withdrawn_key);
+        } else {
+            Self::index_submission(env, mission_id, &hunter);
+        }
+
+        SubNewEvent { mission_id, hunter }.publish(env);
+    }
+
+    fn check_allowlist(
+        env: &Env,
+        mission: &Mission,
+        hunter: &Address,
+        proof: &Vec<BytesN<32>>,
+    ) -> Result<(), QuidError> {
+        let allowed = match &mission.allowlist {
+            Allowlist::Open => true,
+            Allowlist::Addresses(addresses) => addresses.contains(hunter),
+            Allowlist::MerkleRoot(root) => Self::verify_merkle_proof(env, root, hunter, proof),
+        };
+
+        if allowed {
+            Ok(())
+        } else {
+            Err(QuidError::NotAllowlisted)
+        }
+    }
+
+    /// Walk `proof` from the hunter's leaf up to `root`, hashing each pair in
+    /// ascending order.
+    fn verify_merkle_proof(
+        env: &Env,
+        root: &BytesN<32>,
+        hunter: &Address,
+        proof: &Vec<BytesN<32>>,
+    ) -> bool {
+        let mut node: BytesN<32> = env.crypto().sha256(&hunter.clone().to_xdr(env)).into();
+
+        for sibling in proof.iter() {
+            let (left, right) = if node <= sibling {
+                (node, sibling)
+            } else {
+                (sibling, node)
+            };
+            let mut pair = Bytes::from(left);
+            pair.append(&Bytes::from(right));
+            node = env.crypto().sha256(&pair).into();
+        }
+
+        node == *root
+    }
+
+    /// Evaluate the mission's balance requirements for `hunter`.
+    /// Under `GateRule::All` the first unmet requirement is reported by index;
+    /// under `GateRule::Any` failing every requirement is `InsufficientAssetBalance`.
+    fn check_asset_requirements(
+        env: &Env,
+        mission: &Mission,
+        hunter: &Address,
+    ) -> Result<(), QuidError> {
+        if mission.asset_requirements.is_empty() {
+            return Ok(());
+        }
+
+        for (index, requirement) in mission.asset_requirements.iter().enumerate() {
+            let met = token::Client::new(env, &requirement.token).balance(hunter)
+                >= requirement.min_amount;
+            match mission.asset_rule {
+                GateRule::All if !met => {
+                    return Err(QuidError::asset_requirement_unmet(index as u32));
+                }
+                GateRule::Any if met => return Ok(()),
+                _ => {}
+            }
+        }
+
+        match mission.asset_rule {
+            GateRule::All => Ok(()),
+            GateRule::Any => Err(QuidError::InsufficientAssetBalance),
+        }
+    }
+
+    /// Client for reporting marketplace activity, if a reputation contract is
+    /// configured. Reports are best-effort: a failing reputation contract
+    /// never blocks escrow settlement.
+    fn reputation_client(env: &Env) -> Option<ReputationClient<'_>> {
+        Self::get_reputation_contract(env.clone())
+            .ok()
+            .map(|reputation| ReputationClient::new(env, &reputation))
+    }
+
+    /// Query the reputation contract for any criterion of the gate.
+    fn check_reputation(
+        env: &Env,
+        gate: &ReputationGate,
+        hunter: &Address,
+    ) -> Result<(), QuidError> {
+        let reputation = Self::get_reputation_contract(env.clone())?;
+        let client = ReputationClient::new(env, &reputation);
+
+        if gate.min_score.is_some() || gate.min_missions_completed.is_some() {
+            // A hunter without a profile has no score and no completed missions
+            if let Ok(Ok(profile)) = client.try_get_profile(hunter) {
+                if gate.min_score.is_some_and(|min| profile.score >= min)
+                    || gate
+                        .min_missions_completed
+                        .is_some_and(|min| profile.missions_completed >= min)
+                {
+                    return Ok(());
+                }
+            }
+        }
+        if let (Some(attestation_type), Some(issuer)) =
+            (&gate.attestation_type, &gate.attestation_issuer)
+        {
+            if client.has_attestation(hunter, attestation_type, issuer) {
+                return Ok(());
+            }
+        }
+
+        Err(QuidError::InsufficientReputation)
+    }
+
+    fn get_next_mission_id(env: &Env) -> u64 {
+        let mut count: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MissionCount)
+            .unwrap_or(0);
+        count += 1;
+        Self::set_instance(env, &DataKey::MissionCount, &count);
+        count
+    }
+
+    /// Charge the funding fee on an escrow deposit, unless the owner is exempt
+    /// or the protocol is configured to charge on payout instead.
+    fn collect_funding_fee(
+        env: &Env,
+        mission_id: u64,
+        owner: &Address,
+        token: &Address,
+        amount: i128,
+    ) -> Result<(), QuidError> {
+        let fee_config = Self::get_fee_config(env.clone());
+        if fee_config.mode != FeeMode::OnFunding
+            || fee_config.fee_bps == 0
+            || Self::is_fee_exempt(env.clone(), owner.clone())
+        {
+            return Ok(());
+        }
+
+        let fee = Self::compute_fee(&fee_config, amount, fee_config.fee_bps);
+        if fee > 0 {
+            let treasury = Self::get_treasury(env.clone())?;
+            token::Client::new(env, token).transfer(owner, &treasury, &fee);
+            FeeCollectedEvent {
+                mission_id,
+                token: token.clone(),
+                amount: fee,
+            }
+            .publish(env);
+        }
+        Ok(())
+    }
+
+    /// Fee owed on `amount` at `fee_bps`, limited by the configured cap.
+    fn compute_fee(config: &FeeConfig, amount: i128, fee_bps: u32) -> i128 {
+        let fee = amount.saturating_mul(fee_bps as i128) / BPS_DENOMINATOR;
+        if config.max_fee > 0 {
+            fee.min(config.max_fee)
+        } else {
+            fee
+        }
+    }
+
+    /// Validate an owner-driven status change against the mission lifecycle.
+    fn check_transition(from: MissionStatus, to: MissionStatus) -> Result<(), QuidError> {
+        if from.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+        if !from.can_transition_to(to) {
+            return Err(QuidError::InvalidTransition);
+        }
+        Ok(())
+    }
+
+    fn set_status(env: &Env, mission: &mut Mission, to: MissionStatus) {
+        let from = mission.status;
+        mission.status = to;
+
+        MissionStatusEvent {
+            mission_id: mission.id,
+            from,
+            to,
+        }
+        .publish(env);
+    }
+
+    /// Refund remaining reward slots and pending stakes, then mark the mission cancelled.
+    fn cancel(env: &Env, mission: &mut Mission) -> Result<(), QuidError> {
+        if mission.status.is_closed() {
+            return Err(QuidError::MissionClosed);
+        }
+
+        // Hunters still awaiting review get their stakes back through `refund_pending`
+        Self::refund_unused_rewards(env, mission)?;
+
+        Self::set_status(env, mission, MissionStatus::Cancelled);
+        Self::save_mission(env, mission);
+
+        MissionCancelEvent {
+            mission_id: mission.id,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Return the escrow for reward slots that were never paid out.
+    fn refund_unused_rewards(env: &Env, mission: &Mission) -> Result<i128, QuidError> {
+        let refund_amount = Self::outstanding_rewards(mission)?;
+
+        if refund_amount > 0 {
+            Self::release(
+                env,
+                mission.id,
+                &mission.reward_token,
+                &mission.owner,
+                refund_amount,
+            )?;
+        }
+
+        Ok(refund_amount)
+    }
+
+    /// Prize tiers must hold positive amounts and fit within `max_participants`.
+    fn validate_reward_tiers(
+        tiers: &Vec<RewardTier>,
+        max_participants: u32,
+    ) -> Result<(), QuidError> {
+        if tiers.len() > MAX_REWARD_TIERS {
+            return Err(QuidError::InvalidTier);
+        }
+
+        let mut tiered_slots: u32 = 0;
+        for tier in tiers.iter() {
+            if tier.amount <= 0 || tier.count == 0 {
+                return Err(QuidError::InvalidAmount);
+            }
+            tiered_slots = tiered_slots
+                .checked_add(tier.count)
+                .ok_or(QuidError::InvalidAmount)?;
+        }
+        if tiered_slots > max_participants {
+            return Err(QuidError::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    /// Base reward slots that can still be paid out.
+    fn base_slots_remaining(mission: &Mission) -> u32 {
+        let tiered_slots: u32 = mission.reward_tiers.iter().map(|tier| tier.count).sum();
+        let tiered_paid: u32 = mission.tier_paid.iter().sum();
+        let base_paid = mission.participants_count.saturating_sub(tiered_paid);
+
+        mission
             .max_participants
-            .saturating_sub(mission.participants_count);
-        let refund_amount: i128 = (remaining_slots as i128)
+            .saturating_sub(tiered_slots)
+            .saturating_sub(base_paid)
+    }
+
+    /// Escrow still owed across every unpaid tier and base slot.
+    fn outstanding_rewards(mission: &Mission) -> Result<i128, QuidError> {
+        let mut total: i128 = (Self::base_slots_remaining(mission) as i128)
             .checked_mul(mission.reward_amount)
             .ok_or(QuidError::NegativeReward)?;
 
-        if refund_amount > 0 {
-            let token_client = token::Client::new(&env, &mission.reward_token);
-            token_client.transfer(
-                &env.current_contract_address(),
-                &mission.owner,
-                &refund_amount,
-            );
+        for (tier, paid) in mission.reward_tiers.iter().zip(mission.tier_paid.iter()) {
+            let unpaid = tier.count.saturating_sub(paid) as i128;
+            total = unpaid
+                .checked_mul(tier.amount)
+                .and_then(|amount| total.checked_add(amount))
+                .ok_or(QuidError::NegativeReward)?;
+        }
+        Ok(total)
+    }
+
+    fn has_pending_submissions(env: &Env, mission_id: u64) -> bool {
+        Self::get_open_submission_count(env.clone(), mission_id) > 0
+    }
+
+    /// Track `DataKey::OpenSubmissions` as submissions open and resolve.
+    /// Legacy submissions predate the counter, so it saturates at zero.
+    fn count_open_submission(env: &Env, mission_id: u64, opened: bool) {
+        let key = DataKey::OpenSubmissions(mission_id);
+        let count = Self::get_open_submission_count(env.clone(), mission_id);
+        let count = if opened {
+            count + 1
+        } else {
+            count.saturating_sub(1)
+        };
+        Self::persist(env, &key, &count);
+    }
+
+    /// Write a persistent entry and extend its TTL along with the instance's.
+    fn persist<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        Self::extend_persistent(env, key);
+        Self::extend_instance(env);
+    }
+
+    fn set_instance<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().instance().set(key, value);
+        Self::extend_instance(env);
+    }
+
+    fn extend_persistent(env: &Env, key: &DataKey) {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    }
+
+    fn extend_if_present(env: &Env, key: &DataKey) {
+        if env.storage().persistent().has(key) {
+            Self::extend_persistent(env, key);
+        }
+    }
+
+    /// Pull `amount` of `token` into escrow and record it as owed by `mission_id`.
+    /// The received amount is measured rather than assumed: tokens that charge
+    /// a transfer fee or otherwise under-deliver would leave the escrow short.
+    fn escrow_in(
+        env: &Env,
+        mission_id: u64,
+        token: &Address,
+        from: &Address,
+        amount: i128,
+    ) -> Result<(), QuidError> {
+        let token_client = token::Client::new(env, token);
+        let contract = env.current_contract_address();
+        let before = token_client.balance(&contract);
+        token_client.transfer(from, &contract, &amount);
+        if token_client.balance(&contract) - before < amount {
+            return Err(QuidError::TokenUnderDelivered);
+        }
+
+        Self::adjust_escrow(env, mission_id, token, amount)
+    }
+
+    /// Send `amount` of `token` out of the escrow held for `mission_id` and
+    /// drop it from what is owed. A mission can never release more than it
+    /// holds, so one mission's payouts cannot spend another's escrow.
+    fn release(
+        env: &Env,
+        mission_id: u64,
+        token: &Address,
+        to: &Address,
+        amount: i128,
+    ) -> Result<(), QuidError> {
+        Self::adjust_escrow(env, mission_id, token, -amount)?;
+        token::Client::new(env, token).transfer(&env.current_contract_address(), to, &amount);
+        Ok(())
+    }
+
+    /// Move a mission's escrow and the token's liabilities together.
+    fn adjust_escrow(
+        env: &Env,
+        mission_id: u64,
+        token: &Address,
+        delta: i128,
+    ) -> Result<(), QuidError> {
+        Self::backfill_legacy_escrow(env, mission_id);
+
+        let key = DataKey::Escrow(mission_id, token.clone());
+        let held = Self::get_escrow(env.clone(), mission_id, token.clone())
+            .checked_add(delta)
+            .ok_or(QuidError::InvalidAmount)?;
+        if held < 0 {
+            return Err(QuidError::InsufficientFunds);
+        }
+        Self::persist(env, &key, &held);
+        Self::adjust_liabilities(env, token, delta)
+    }
+
+    fn adjust_liabilities(env: &Env, token: &Address, delta: i128) -> Result<(), QuidError> {
+        let key = DataKey::Liabilities(token.clone());
+        let total = Self::get_liabilities(env.clone(), token.clone())
+            .checked_add(delta)
+            .ok_or(QuidError::InvalidAmount)?;
+        if total < 0 {
+            return Err(QuidError::InsufficientFunds);
+        }
+        Self::persist(env, &key, &total);
+        Ok(())
+    }
+
+    /// Record the reward escrow of a mission written before escrow was
+    /// tracked, the first time it is touched. Legacy missions paid each
+    /// reward straight out of escrow, so what remains is the unpaid slots of
+    /// a mission that was never closed. Legacy stakes are left out: they are
+    /// returned through `settle_legacy_stake`.
+    fn backfill_legacy_escrow(env: &Env, mission_id: u64) {
+        let Some(VersionedMission::V1(legacy)) = Self::read_versioned_mission(env, mission_id)
+        else {
+            return;
+        };
+        let key = DataKey::Escrow(mission_id, legacy.reward_token.clone());
+        if env.storage().persistent().has(&key) {
+            return;
+        }
+
+        let mission = legacy.migrate(env);
+        let held = if mission.status.is_closed() {
+            0
+        } else {
+            Self::outstanding_rewards(&mission).unwrap_or(0)
+        };
+        Self::persist(env, &key, &held);
+        let total = Self::get_liabilities(env.clone(), mission.reward_token.clone());
+        Self::persist(
+            env,
+            &DataKey::Liabilities(mission.reward_token),
+            &(total + held),
+        );
+    }
+
+    fn extend_instance(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(LIFETIME_THRESHOLD, BUMP_AMOUNT);
+    }
+
+    /// Read a mission stored in any schema, upgraded to the current layout.
+    fn read_mission(env: &Env, mission_id: u64) -> Option<Mission> {
+        Self::read_versioned_mission(env, mission_id).map(|entry| entry.into_current(env))
+    }
+
+    /// Entries written before versioning are bare `MissionV1` structs.
+    fn read_versioned_mission(env: &Env, mission_id: u64) -> Option<VersionedMission> {
+        let raw: Val = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Mission(mission_id))?;
+        VersionedMission::try_from_val(env, &raw)
+            .or_else(|_| MissionV1::try_from_val(env, &raw).map(VersionedMission::V1))
+            .ok()
+    }
+
+    fn save_mission(env: &Env, mission: &Mission) {
+        Self::backfill_legacy_escrow(env, mission.id);
+        Self::persist(
+            env,
+            &DataKey::Mission(mission.id),
+            &VersionedMission::V2(mission.clone()),
+        );
+    }
+
+    /// Read a submission stored in any schema, upgraded to the current layout.
+    fn read_submission(env: &Env, mission_id: u64, hunter: &Address) -> Option<Submission> {
+        let raw: Val = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Submission(mission_id, hunter.clone()))?;
+        VersionedSubmission::try_from_val(env, &raw)
+            .or_else(|_| SubmissionV1::try_from_val(env, &raw).map(VersionedSubmission::V1))
+            .ok()
+            .map(VersionedSubmission::into_current)
+    }
+
+    fn save_submission(env: &Env, mission_id: u64, submission: &Submission) {
+        let was_open = Self::read_submission(env, mission_id, &submission.hunter)
+            .is_some_and(|previous| previous.status.is_open());
+        if was_open != submission.status.is_open() {
+            Self::count_open_submission(env, mission_id, !was_open);
+        }
+        Self::persist(
+            env,
+            &DataKey::Submission(mission_id, submission.hunter.clone()),
+            &VersionedSubmission::V2(submission.clone()),
+        );
+    }
+
+    /// Load a submission that is still awaiting review.
+    fn get_pending_submission(
+        env: &Env,
+        mission_id: u64,
+        hunter: &Address,
+    ) -> Result<Submission, QuidError> {
+        let submission =
+            Self::read_submission(env, mission_id, hunter).ok_or(QuidError::SubmissionNotFound)?;
+
+        if submission.status == SubmissionStatus::Paid {
+            return Err(QuidError::AlreadyPaid);
+        }
+        if submission.status != SubmissionStatus::Pending {
+            return Err(QuidError::NotPending);
+        }
+        Ok(submission)
+    }
+
+    /// Settle a submission and record the reward as claimable by the hunter.
+    /// The caller is responsible for persisting the updated mission.
+    fn approve(
+        env: &Env,
+        mission: &mut Mission,
+        hunter: Address,
+        submission: &mut Submission,
+        tier: Option<u32>,
+    ) -> Result<(), QuidError> {
+        let amount = Self::settle_submission(env, mission, &hunter, submission, tier)?;
+
+        // The winner's stake is returned alongside the reward
+        let stake_key = DataKey::HunterStake(mission.id, hunter.clone());
+        let stake_amount: i128 = match &mission.stake_token {
+            Some(_) => env.storage().persistent().get(&stake_key).unwrap_or(0),
+            None => 0,
+        };
+        if stake_amount > 0 {
+            env.storage().persistent().remove(&stake_key);
+        }
+
+        let key = DataKey::Claimable(mission.id, hunter.clone());
+        let claimable = ClaimableBalance {
+            mission_id: mission.id,
+            token: mission.reward_token.clone(),
+            amount,
+            stake_token: mission.stake_token.clone(),
+            stake_amount,
+        };
+        Self::persist(env, &key, &claimable);
+        let claims_key = DataKey::HunterClaims(hunter.clone());
+        let mut claims: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&claims_key)
+            .unwrap_or(Vec::new(env));
+        claims.push_back(mission.id);
+        Self::persist(env, &claims_key, &claims);
+        SubApprovedEvent {
+            mission_id: mission.id,
+            hunter,
+            amount,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// First prize tier with unpaid slots, or `None` for the base reward.
+    fn next_open_tier(mission: &Mission) -> Option<u32> {
+        (0..mission.reward_tiers.len()).find(|&index| {
+            let count = mission.reward_tiers.get(index).map_or(0, |tier| tier.count);
+            mission.tier_paid.get(index).unwrap_or(0) < count
+        })
+    }
+
+    /// Consume a reward slot for `tier`, take the payout fee and mark the
+    /// submission approved. Returns the reward owed to the hunter, which the
+    /// caller records. The caller is responsible for persisting the updated mission.
+    fn settle_submission(
+        env: &Env,
+        mission: &mut Mission,
+        hunter: &Address,
+        submission: &mut Submission,
+        tier: Option<u32>,
+    ) -> Result<i128, QuidError> {
+        let reward_amount = match tier {
+            Some(index) => {
+                let reward_tier = mission
+                    .reward_tiers
+                    .get(index)
+                    .ok_or(QuidError::InvalidTier)?;
+                if mission.tier_paid.get(index).unwrap_or(0) >= reward_tier.count {
+                    return Err(QuidError::TierFull);
+                }
+                reward_tier.amount
+            }
+            None => {
+                if Self::base_slots_remaining(mission) == 0 {
+                    return Err(QuidError::TierFull);
+                }
+                mission.reward_amount
+            }
+        };
+
+        // Missions funded under `FeeMode::OnPayout` pay the fee out of each reward
+        let fee = if mission.payout_fee_bps > 0 {
+            let fee_config = Self::get_fee_config(env.clone());
+            Self::compute_fee(&fee_config, reward_amount, mission.payout_fee_bps)
+        } else {
+            0
+        };
+
+        if fee > 0 {
+            let treasury = Self::get_treasury(env.clone())?;
+            Self::release(env, mission.id, &mission.reward_token, &treasury, fee)?;
+            FeeCollectedEvent {
+                mission_id: mission.id,
+                token: mission.reward_token.clone(),
+                amount: fee,
+            }
+            .publish(env);
+        }
+
+        submission.status = SubmissionStatus::Approved;
+        Self::save_submission(env, mission.id, submission);
+
+        if let Some(index) = tier {
+            let paid = mission.tier_paid.get(index).unwrap_or(0);
+            mission.tier_paid.set(index, paid + 1);
+        }
+        mission.participants_count += 1;
+        if mission.max_participants > 0 && mission.participants_count >= mission.max_participants {
+            Self::set_status(env, mission, MissionStatus::Completed);
         }
 
-        mission.status = MissionStatus::Cancelled;
-        env.storage()
+        // Owners cannot credit themselves, a token anyone could mint for free
+        // earns nothing, and neither do dust rewards
+        if *hunter != mission.owner
+            && Self::is_token_vetted(env.clone(), mission.reward_token.clone())
+            && reward_amount >= Self::min_reputation_reward(env, &mission.reward_token)
+        {
+            if let Some(reputation) = Self::reputation_client(env) {
+                let reporter = env.current_contract_address();
+                let _ = reputation.try_record_mission_completed(
+                    &reporter,
+                    hunter,
+                    &REPUTATION_PAYOUT_SCORE,
+                );
+                // The owner is credited on the first qualifying payout, so a
+                // mission cancelled for a full refund earns nothing
+                let credited_key = DataKey::OwnerCredited(mission.id);
+                if !env.storage().persistent().has(&credited_key)
+                    && reputation
+                        .try_record_mission_created(&reporter, &mission.owner)
+                        .is_ok()
+                {
+                    Self::persist(env, &credited_key, &true);
+                }
+            }
+        }
+
+        Ok(reward_amount - fee)
+    }
+
+    /// Smallest reward, in base units of `token`, that earns reputation: one
+    /// whole unit at the token's own decimals.
+    fn min_reputation_reward(env: &Env, token: &Address) -> i128 {
+        let decimals = token::Client::new(env, token).decimals();
+        10i128.checked_pow(decimals).unwrap_or(i128::MAX)
+    }
+
+    /// Transfer an approved reward and stake to the hunter and mark the
+    /// submission paid.
+    /// The caller is responsible for updating the hunter's claim index.
+    fn claim(env: &Env, mission_id: u64, hunter: &Address) -> Result<i128, QuidError> {
+        let key = DataKey::Claimable(mission_id, hunter.clone());
+        let claimable: ClaimableBalance = env
+            .storage()
             .persistent()
-            .set(&DataKey::Mission(mission_id), &mission);
+            .get(&key)
+            .ok_or(QuidError::NothingToClaim)?;
+        env.storage().persistent().remove(&key);
+
+        Self::release(env, mission_id, &claimable.token, hunter, claimable.amount)?;
+        if let Some(stake_token) = &claimable.stake_token {
+            if claimable.stake_amount > 0 {
+                Self::release(env, mission_id, stake_token, hunter, claimable.stake_amount)?;
+            }
+        }
 
-        MissionCancelEvent { mission_id }.publish(&env);
+        if let Some(mut submission) = Self::read_submission(env, mission_id, hunter) {
+            submission.status = SubmissionStatus::Paid;
+            Self::save_submission(env, mission_id, &submission);
+        }
 
-        Ok(())
+        RewardClaimEvent {
+            mission_id,
+            hunter: hunter.clone(),
+            amount: claimable.amount,
+        }
+        .publish(env);
+
+        Ok(claimable.amount)
     }
 
-    pub fn pause_mission(env: Env, id: u64) -> Result<(), QuidError> {
-        let mut mission = Self::get_mission(env.clone(), id)?;
-        mission.owner.require_auth();
-        mission.status = MissionStatus::Paused;
-        env.storage()
+    /// Record the hunter under the next per-mission submission index.
+    fn index_submission(env: &Env, mission_id: u64, hunter: &Address) {
+        let count_key = DataKey::SubmissionCount(mission_id);
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+
+        let index_key = DataKey::SubmissionIndex(mission_id, count);
+        Self::persist(env, &index_key, hunter);
+        Self::persist(env, &count_key, &(count + 1));
+    }
+
+    /// Index of `mission_id` among its owner's missions. Owners' missions are
+    /// indexed in creation order, so their ids increase with the index.
+    fn find_owner_mission(env: &Env, owner: &Address, mission_id: u64) -> Option<u32> {
+        let count: u32 = env
+            .storage()
             .persistent()
-            .set(&DataKey::Mission(id), &mission);
+            .get(&DataKey::OwnerMissionCount(owner.clone()))
+            .unwrap_or(0);
 
-        MissionPauseEvent { mission_id: id }.publish(&env);
+        let (mut low, mut high) = (0, count);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let id: u64 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::OwnerMission(owner.clone(), mid))?;
+            match id.cmp(&mission_id) {
+                core::cmp::Ordering::Equal => return Some(mid),
+                core::cmp::Ordering::Less => low = mid + 1,
+                core::cmp::Ordering::Greater => high = mid,
+            }
+        }
+        None
+    }
+
+    /// Record the mission under the next index for its owner.
+    fn index_owner_mission(env: &Env, owner: &Address, mission_id: u64) {
+        let count_key = DataKey::OwnerMissionCount(owner.clone());
+        let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+
+        let index_key = DataKey::OwnerMission(owner.clone(), count);
+        Self::persist(env, &index_key, &mission_id);
+        Self::persist(env, &count_key, &(count + 1));
+    }
+
+    /// Set the contract admin at deploy time, so no one can claim the role
+    /// between deployment and a separate initialization call. A fresh
+    /// deployment only ever writes the current schema.
+    pub fn __constructor(env: Env, admin: Address) {
+        Self::set_instance(&env, &DataKey::Admin, &admin);
+        Self::set_instance(&env, &DataKey::SchemaVersion, &SCHEMA_VERSION);
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, QuidError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(QuidError::NotInitialized)
+    }
+
+    /// Appoint the first admin on a contract upgraded from before admins
+    /// existed, where the constructor never ran. The treasury was the only
+    /// trust root of those deployments, so it must authorize. Fails with
+    /// `AlreadyInitialized` once any admin is set.
+    pub fn bootstrap_admin(env: Env, admin: Address) -> Result<(), QuidError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(QuidError::AlreadyInitialized);
+        }
+        let treasury = Self::get_treasury(env.clone())?;
+        treasury.require_auth();
+
+        Self::set_instance(&env, &DataKey::Admin, &admin);
+
+        AdminAcceptEvent { admin }.publish(&env);
         Ok(())
     }
 
-    pub fn update_mission_status(
-        env: Env,
-        mission_id: u64,
-        new_status: MissionStatus,
-    ) -> Result<(), QuidError> {
-        let mut mission = Self::get_mission(env.clone(), mission_id)?;
-        mission.owner.require_auth();
-        mission.status = new_status;
+    /// Start handing the admin role to `new_admin`, who must call `accept_admin`.
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), QuidError> {
+        let admin = Self::require_admin(&env)?;
+
+        Self::set_instance(&env, &DataKey::PendingAdmin, &new_admin);
+
+        AdminProposeEvent {
+            admin,
+            pending_admin: new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Complete an admin rotation. The proposed admin must authorize.
+    pub fn accept_admin(env: Env) -> Result<(), QuidError> {
+        let pending_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(QuidError::InvalidState)?;
+        pending_admin.require_auth();
+
+        Self::set_instance(&env, &DataKey::Admin, &pending_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        AdminAcceptEvent {
+            admin: pending_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Replace the contract code. Storage is kept as is.
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), QuidError> {
+        Self::require_admin(&env)?;
+
+        env.deployer()
+            .update_current_contract_wasm(wasm_hash.clone());
+
+        ContractUpgradeEvent { wasm_hash }.publish(&env);
+        Ok(())
+    }
+
+    /// Emergency stop. New missions, submissions, funding and payouts are
+    /// refused; cancellations, expiry, rejections and withdrawals still work
+    /// so funds can always be returned.
+    pub fn pause(env: Env) -> Result<(), QuidError> {
+        Self::set_paused(&env, true)
+    }
+
+    pub fn unpause(env: Env) -> Result<(), QuidError> {
+        Self::set_paused(&env, false)
+    }
+
+    pub fn is_paused(env: Env) -> bool {
         env.storage()
-            .persistent()
-            .set(&DataKey::Mission(mission_id), &mission);
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
+    }
+
+    fn set_paused(env: &Env, paused: bool) -> Result<(), QuidError> {
+        Self::require_admin(env)?;
+
+        Self::set_instance(env, &DataKey::Paused, &paused);
+
+        ContractPauseEvent { paused }.publish(env);
         Ok(())
     }
 
-    /// Slash a hunter's stake for spam submissions.
-    /// Only the mission owner may invoke this.
-    pub fn slash_hunter_stake(
-        env: Env,
-        mission_id: u64,
-        hunter: Address,
-        stake_token: Address,
-    ) -> Result<(), QuidError> {
-        let mission = Self::get_mission(env.clone(), mission_id)?;
-        mission.owner.require_auth();
-        Self::slash_stake(&env, mission_id, hunter, stake_token)
+    /// Restrict new missions to vetted reward and stake tokens.
+    pub fn set_token_allowlist_enabled(env: Env, enabled: bool) -> Result<(), QuidError> {
+        Self::require_admin(&env)?;
+
+        Self::set_instance(&env, &DataKey::TokenAllowlistEnabled, &enabled);
+
+        TokenAllowlistEvent { enabled }.publish(&env);
+        Ok(())
     }
 
-    pub fn get_mission_count(env: Env) -> u64 {
+    pub fn is_token_allowlist_enabled(env: Env) -> bool {
         env.storage()
             .instance()
-            .get(&DataKey::MissionCount)
-            .unwrap_or(0)
+            .get(&DataKey::TokenAllowlistEnabled)
+            .unwrap_or(false)
     }
 
-    pub fn mission_exists(env: Env, mission_id: u64) -> bool {
+    /// Mark a token as vetted (standard transfer semantics) or revoke it.
+    /// Revoking does not affect missions already escrowed in the token.
+    pub fn set_token_vetted(env: Env, token: Address, vetted: bool) -> Result<(), QuidError> {
+        Self::require_admin(&env)?;
+
+        let key = DataKey::VettedToken(token.clone());
+        if vetted {
+            Self::persist(&env, &key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        TokenVetEvent { token, vetted }.publish(&env);
+        Ok(())
+    }
+
+    pub fn is_token_vetted(env: Env, token: Address) -> bool {
         env.storage()
             .persistent()
-            .has(&DataKey::Mission(mission_id))
+            .get(&DataKey::VettedToken(token))
+            .unwrap_or(false)
     }
 
-    fn validate_mission_params(_title: &String, reward_amount: i128) -> Result<(), QuidError> {
-        if reward_amount <= 0 {
-            return Err(QuidError::NegativeReward);
+    fn require_vetted_token(env: &Env, token: &Address) -> Result<(), QuidError> {
+        if Self::is_token_allowlist_enabled(env.clone())
+            && !Self::is_token_vetted(env.clone(), token.clone())
+        {
+            return Err(QuidError::TokenNotVetted);
         }
         Ok(())
     }
 
-    fn get_next_mission_id(env: &Env) -> u64 {
-        let mut count: u64 = env
+    fn require_admin(env: &Env) -> Result<Address, QuidError> {
+        let admin = Self::get_admin(env.clone())?;
+        admin.require_auth();
+        Ok(admin)
+    }
+
+    fn require_not_paused(env: &Env) -> Result<(), QuidError> {
+        if Self::is_paused(env.clone()) {
+            return Err(QuidError::ContractPaused);
+        }
+        Ok(())
+    }
+
+    /// Schema version every stored mission is known to use. Contracts deployed
+    /// before versioning never recorded one, so they report 1 until `migrate`
+    /// has run to completion.
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(1)
+    }
+
+    /// Rewrite up to `batch` missions in the current schema, resuming where
+    /// the previous call stopped, and return how many are left to check.
+    /// The schema version is bumped once every mission has been visited.
+    /// Legacy submissions are keyed by hunter and were never indexed, so they
+    /// cannot be enumerated here: they are upgraded lazily the next time they
+    /// are written, never appear in `list_submissions`, are not counted as
+    /// open, and are not visited by `refund_pending`. Their stakes are frozen
+    /// until the admin calls `settle_legacy_stake`.
+    pub fn migrate(env: Env, batch: u32) -> Result<u64, QuidError> {
+        Self::require_admin(&env)?;
+
+        let count = Self::get_mission_count(env.clone());
+        let cursor: u64 = env
             .storage()
             .instance()
-            .get(&DataKey::MissionCount)
+            .get(&DataKey::MigrationCursor)
             .unwrap_or(0);
-        count += 1;
-        env.storage().instance().set(&DataKey::MissionCount, &count);
-        count
+        let end = cursor
+            .saturating_add(batch.min(MAX_PAGE_SIZE) as u64)
+            .min(count);
+
+        for mission_id in cursor.saturating_add(1)..=end {
+            if let Some(entry) = Self::read_versioned_mission(&env, mission_id) {
+                if !entry.is_current() {
+                    Self::save_mission(&env, &entry.into_current(&env));
+                }
+            }
+        }
+
+        if end == count {
+            Self::set_instance(&env, &DataKey::SchemaVersion, &SCHEMA_VERSION);
+            env.storage().instance().remove(&DataKey::MigrationCursor);
+        } else {
+            Self::set_instance(&env, &DataKey::MigrationCursor, &end);
+        }
+
+        Ok(count - end)
+    }
+
+    /// Return a stake taken before stake policies existed. Legacy hunters
+    /// picked their own stake token and it was never stored, so the admin
+    /// supplies `token` after checking the hunter's original transfer.
+    /// Fails with `InsufficientFunds` if paying it would leave `token`
+    /// short of the liabilities.
+    /// Only missions written before versioning hold such stakes.
+    pub fn settle_legacy_stake(
+        env: Env,
+        mission_id: u64,
+        hunter: Address,
+        token: Address,
+    ) -> Result<i128, QuidError> {
+        Self::require_admin(&env)?;
+
+        let mission = Self::get_mission(env.clone(), mission_id)?;
+        if mission.stake_token.is_some() {
+            return Err(QuidError::InvalidState);
+        }
+
+        let key = DataKey::HunterStake(mission_id, hunter.clone());
+        let amount: i128 = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(QuidError::StakeNotFound)?;
+        env.storage().persistent().remove(&key);
+
+        // Legacy stakes were never counted in the liabilities, so a wrong
+        // `token` could pay out of other missions' escrow
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &hunter,
+            &amount,
+        );
+        if !Self::check_solvency(env.clone(), token.clone()) {
+            return Err(QuidError::InsufficientFunds);
+        }
+
+        LegacyStakeSettleEvent {
+            mission_id,
+            hunter,
+            token,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(amount)
     }
 
     /// Set the protocol treasury address. Must be called by the treasury itself.
@@ -414,9 +2932,86 @@ impl QuidStoreContract {
             new_treasury.require_auth();
         }
 
+        Self::set_instance(&env, &DataKey::Treasury, &new_treasury);
+    }
+
+    /// Appoint the arbiter who resolves slash appeals. Only the admin may invoke this.
+    pub fn set_arbiter(env: Env, arbiter: Address) -> Result<(), QuidError> {
+        Self::require_admin(&env)?;
+
+        Self::set_instance(&env, &DataKey::Arbiter, &arbiter);
+        Ok(())
+    }
+
+    pub fn get_arbiter(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Arbiter)
+    }
+
+    /// Point reputation-gated missions at a `QuidReputationContract`.
+    /// Only the admin may invoke this.
+    pub fn set_reputation_contract(env: Env, contract: Address) -> Result<(), QuidError> {
+        Self::require_admin(&env)?;
+
+        Self::set_instance(&env, &DataKey::ReputationContract, &contract);
+        Ok(())
+    }
+
+    pub fn get_reputation_contract(env: Env) -> Result<Address, QuidError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReputationContract)
+            .ok_or(QuidError::ReputationNotSet)
+    }
+
+    /// Configure the protocol fee. Only the admin may invoke this, and a
+    /// treasury must be set to receive the fees.
+    pub fn set_fee_config(env: Env, config: FeeConfig) -> Result<(), QuidError> {
+        Self::require_admin(&env)?;
+        Self::get_treasury(env.clone())?;
+
+        if config.fee_bps > MAX_FEE_BPS
+            || config.withdrawal_fee_bps > MAX_FEE_BPS
+            || config.max_fee < 0
+        {
+            return Err(QuidError::InvalidFee);
+        }
+
+        Self::set_instance(&env, &DataKey::FeeConfig, &config);
+        Ok(())
+    }
+
+    /// Get the protocol fee configuration. Defaults to no fee.
+    pub fn get_fee_config(env: Env) -> FeeConfig {
         env.storage()
             .instance()
-            .set(&DataKey::Treasury, &new_treasury);
+            .get(&DataKey::FeeConfig)
+            .unwrap_or(FeeConfig {
+                fee_bps: 0,
+                max_fee: 0,
+                mode: FeeMode::OnFunding,
+                withdrawal_fee_bps: 0,
+            })
+    }
+
+    /// Add or remove a founder from the fee-exempt allowlist.
+    /// Only the admin may invoke this.
+    pub fn set_fee_exempt(env: Env, founder: Address, exempt: bool) -> Result<(), QuidError> {
+        Self::require_admin(&env)?;
+
+        let key = DataKey::FeeExempt(founder);
+        if exempt {
+            Self::persist(&env, &key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    pub fn is_fee_exempt(env: Env, founder: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FeeExempt(founder))
+            .unwrap_or(false)
     }
 
     /// Get the protocol treasury address.
@@ -427,14 +3022,43 @@ impl QuidStoreContract {
             .ok_or(QuidError::TreasuryNotSet)
     }
 
-    /// Slash a hunter's stake by sending it to the protocol treasury.
+    /// Amount of `token` the contract owes: unpaid reward escrow, approved
+    /// claims, held stakes and pending slashes.
+    pub fn get_liabilities(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Liabilities(token))
+            .unwrap_or(0)
+    }
+
+    /// Amount of `token` held for one mission.
+    pub fn get_escrow(env: Env, mission_id: u64, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Escrow(mission_id, token))
+            .unwrap_or(0)
+    }
+
+    /// Whether the contract's balance of `token` covers its liabilities.
+    pub fn check_solvency(env: Env, token: Address) -> bool {
+        let balance = token::Client::new(&env, &token).balance(&env.current_contract_address());
+        balance >= Self::get_liabilities(env, token)
+    }
+
+    /// Move a hunter's stake into a pending slash record and close the
+    /// submission. Only pending submissions can be slashed, and never while an
+    /// earlier slash for the same hunter is still unsettled.
     fn slash_stake(
         env: &Env,
-        mission_id: u64,
+        mission: &Mission,
         hunter: Address,
-        stake_token: Address,
+        reason_cid: &String,
     ) -> Result<(), QuidError> {
-        let key = DataKey::HunterStake(mission_id, hunter);
+        let stake_token = mission
+            .stake_token
+            .as_ref()
+            .ok_or(QuidError::StakeNotFound)?;
+        let key = DataKey::HunterStake(mission.id, hunter.clone());
 
         let amount: i128 = env
             .storage()
@@ -442,36 +3066,136 @@ impl QuidStoreContract {
             .get(&key)
             .ok_or(QuidError::StakeNotFound)?;
 
-        let treasury = Self::get_treasury(env.clone())?;
+        let mut submission =
+            Self::read_submission(env, mission.id, &hunter).ok_or(QuidError::SubmissionNotFound)?;
+        // A sealed commitment shows nothing to judge; an unrevealed one is
+        // forfeited through `forfeit_unrevealed` instead
+        if submission.status != SubmissionStatus::Pending {
+            return Err(QuidError::NotPending);
+        }
 
-        token::Client::new(env, &stake_token).transfer(
-            &env.current_contract_address(),
-            &treasury,
-            &amount,
-        );
+        // An unsettled record still holds an earlier stake; never overwrite it
+        let slash_key = DataKey::Slash(mission.id, hunter.clone());
+        if let Some(existing) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, SlashRecord>(&slash_key)
+        {
+            if matches!(
+                existing.status,
+                SlashStatus::Pending | SlashStatus::Appealed
+            ) {
+                return Err(QuidError::InvalidState);
+            }
+        }
+
+        // The treasury must exist for the slash to be finalized later
+        Self::get_treasury(env.clone())?;
 
         env.storage().persistent().remove(&key);
 
+        submission.status = SubmissionStatus::Slashed;
+        Self::save_submission(env, mission.id, &submission);
+
+        // Hold the stake until the dispute window closes or an appeal is resolved
+        let record = SlashRecord {
+            token: stake_token.clone(),
+            amount,
+            reason_cid: reason_cid.clone(),
+            slashed_at: env.ledger().timestamp(),
+            evidence_cid: None,
+            status: SlashStatus::Pending,
+        };
+        Self::persist(env, &slash_key, &record);
+        SlashPendingEvent {
+            mission_id: mission.id,
+            hunter,
+            amount,
+            reason_cid: reason_cid.clone(),
+        }
+        .publish(env);
+
         Ok(())
     }
 
-    /// Private function
-    /// Refund a hunter's stake back to them.
-    /// Used during payout (for winners) and rejection (for honest losers).
-    fn refund_stake(
+    /// Release a held stake to the treasury or back to the hunter.
+    fn settle_slash(
         env: &Env,
         mission_id: u64,
         hunter: Address,
-        stake_token: Address,
+        mut record: SlashRecord,
+        status: SlashStatus,
+        treasury: &Address,
     ) -> Result<(), QuidError> {
-        let key = DataKey::HunterStake(mission_id, hunter.clone());
+        let recipient = if status == SlashStatus::Upheld {
+            treasury.clone()
+        } else {
+            hunter.clone()
+        };
+        Self::release(env, mission_id, &record.token, &recipient, record.amount)?;
+
+        record.status = status;
+        Self::persist(env, &DataKey::Slash(mission_id, hunter.clone()), &record);
+
+        if status == SlashStatus::Upheld {
+            if let Some(reputation) = Self::reputation_client(env) {
+                let _ = reputation.try_record_slash(
+                    &env.current_contract_address(),
+                    &hunter,
+                    &REPUTATION_SLASH_PENALTY,
+                );
+            }
+        }
+
+        SlashResolveEvent {
+            mission_id,
+            hunter,
+            status,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Send an unrevealed commitment's stake to the treasury.
+    fn forfeit_commitment(env: &Env, mission: &Mission, hunter: Address) -> Result<(), QuidError> {
+        let key = DataKey::HunterStake(mission.id, hunter.clone());
+        let amount = match (
+            &mission.stake_token,
+            env.storage().persistent().get::<DataKey, i128>(&key),
+        ) {
+            (Some(stake_token), Some(amount)) => {
+                let treasury = Self::get_treasury(env.clone())?;
+                Self::release(env, mission.id, stake_token, &treasury, amount)?;
+                env.storage().persistent().remove(&key);
+                amount
+            }
+            _ => 0,
+        };
+
+        SubForfeitEvent {
+            mission_id: mission.id,
+            hunter,
+            amount,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Private function
+    /// Refund a hunter's stake back to them.
+    /// Used when a submission is rejected, withdrawn or left unreviewed; winners
+    /// reclaim their stake through `claim_reward`.
+    fn refund_stake(env: &Env, mission: &Mission, hunter: Address) -> Result<(), QuidError> {
+        let Some(stake_token) = &mission.stake_token else {
+            // Zero-stake mission: nothing was ever escrowed
+            return Ok(());
+        };
+        let key = DataKey::HunterStake(mission.id, hunter.clone());
 
         if let Some(amount) = env.storage().persistent().get::<DataKey, i128>(&key) {
-            token::Client::new(env, &stake_token).transfer(
-                &env.current_contract_address(),
-                &hunter,
-                &amount,
-            );
+            Self::release(env, mission.id, stake_token, &hunter, amount)?;
 
             env.storage().persistent().remove(&key);
         }
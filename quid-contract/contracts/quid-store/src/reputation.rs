@@ -0,0 +1,27 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, String};
+
+/// Mirror of the reputation contract's `Profile`; fields must stay in sync.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Profile {
+    pub subject: Address,
+    pub score: i64,
+    pub missions_completed: u32,
+    pub missions_created: u32,
+}
+
+/// Entry points of `QuidReputationContract` called by the store.
+#[allow(dead_code)]
+#[contractclient(name = "ReputationClient")]
+pub trait ReputationInterface {
+    fn get_profile(env: Env, subject: Address) -> Profile;
+    fn has_attestation(
+        env: Env,
+        subject: Address,
+        attestation_type: String,
+        issuer: Address,
+    ) -> bool;
+    fn record_mission_created(env: Env, reporter: Address, subject: Address);
+    fn record_mission_completed(env: Env, reporter: Address, subject: Address, score_delta: i64);
+    fn record_slash(env: Env, reporter: Address, subject: Address, penalty: i64);
+}
@@ -1,15 +1,28 @@
 #![cfg(test)]
 
 use super::*;
-use crate::types::MissionStatus;
+use crate::types::{
+    ClaimableBalance, FeeConfig, FeeMode, MissionRole, MissionStatus, RewardTier, SlashStatus,
+    SubmissionStatus,
+};
 use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{
+        storage::{Instance as _, Persistent as _},
+        Address as _, Events, IssuerFlags, Ledger,
+    },
+    Address, Env, Error, IntoVal, InvokeError, String, Symbol, Val,
+};
+
+const DAY: u64 = 24 * 60 * 60;
+const WEEK: u64 = 7 * DAY;
 
 fn setup_test_env() -> (Env, Address, Address, Address) {
     let env = Env::default();
     env.mock_all_auths();
 
-    let contract_id = env.register(QuidStoreContract, ());
+    let contract_id = env.register(QuidStoreContract, (Address::generate(&env),));
     let token_admin = Address::generate(&env);
     let token_contract = env.register_stellar_asset_contract_v2(token_admin.clone());
     let token_address = token_contract.address();
@@ -20,11 +33,155 @@ fn setup_test_env() -> (Env, Address, Address, Address) {
     (env, contract_id, owner, token_address)
 }
 
+fn default_schedule(env: &Env) -> Schedule {
+    let now = env.ledger().timestamp();
+    Schedule {
+        submission_deadline: now + WEEK,
+        review_deadline: now + 2 * WEEK,
+        review_window: 3 * DAY,
+        auto_approve: false,
+        reveal_deadline: 0,
+    }
+}
+
 fn mint_tokens_for_hunter(env: &Env, token_address: &Address, hunter: &Address, amount: i128) {
     let token_admin_client = StellarAssetClient::new(env, token_address);
     token_admin_client.mint(hunter, &amount);
 }
 
+/// Mission terms for tests: a 100 unit base reward, five slots, a 10 unit
+/// stake in the reward token, no gates and `default_schedule`. Tests override
+/// only the terms they exercise.
+struct MissionBuilder {
+    params: MissionParams,
+}
+
+impl MissionBuilder {
+    fn new(env: &Env, token_address: &Address) -> Self {
+        let params = MissionParams {
+            reward: Reward {
+                reward_token: token_address.clone(),
+                reward_amount: 100,
+                tiers: Vec::new(env),
+                raffle: false,
+            },
+            max_participants: 5,
+            min_asset: MinAsset {
+                requirements: Vec::new(env),
+                rule: GateRule::All,
+                reputation: ReputationGate::default(),
+                allowlist: Allowlist::Open,
+            },
+            stake_policy: StakePolicy {
+                stake_token: Some(token_address.clone()),
+                stake_amount: 10,
+            },
+            schedule: default_schedule(env),
+        };
+        MissionBuilder { params }
+    }
+
+    fn reward(mut self, amount: i128) -> Self {
+        self.params.reward.reward_amount = amount;
+        self
+    }
+
+    fn tiers(mut self, tiers: Vec<RewardTier>) -> Self {
+        self.params.reward.tiers = tiers;
+        self
+    }
+
+    fn raffle(mut self) -> Self {
+        self.params.reward.raffle = true;
+        self
+    }
+
+    fn slots(mut self, slots: u32) -> Self {
+        self.params.max_participants = slots;
+        self
+    }
+
+    fn requirements(mut self, requirements: Vec<AssetRequirement>) -> Self {
+        self.params.min_asset.requirements = requirements;
+        self
+    }
+
+    fn rule(mut self, rule: GateRule) -> Self {
+        self.params.min_asset.rule = rule;
+        self
+    }
+
+    fn reputation(mut self, gate: ReputationGate) -> Self {
+        self.params.min_asset.reputation = gate;
+        self
+    }
+
+    fn allowlist(mut self, allowlist: Allowlist) -> Self {
+        self.params.min_asset.allowlist = allowlist;
+        self
+    }
+
+    fn stake_token(mut self, stake_token: Option<Address>) -> Self {
+        self.params.stake_policy.stake_token = stake_token;
+        self
+    }
+
+    fn stake_amount(mut self, amount: i128) -> Self {
+        self.params.stake_policy.stake_amount = amount;
+        self
+    }
+
+    /// Zero-stake mode.
+    fn no_stake(self) -> Self {
+        self.stake_token(None).stake_amount(0)
+    }
+
+    fn schedule(mut self, schedule: Schedule) -> Self {
+        self.params.schedule = schedule;
+        self
+    }
+
+    /// Hunters commit until submissions close and reveal over the next two days.
+    fn commit_reveal(mut self) -> Self {
+        self.params.schedule.reveal_deadline = self.params.schedule.submission_deadline + 2 * DAY;
+        self
+    }
+
+    fn create(&self, client: &QuidStoreContractClient, owner: &Address) -> u64 {
+        client.create_mission(
+            owner,
+            &String::from_str(&client.env, "Mission"),
+            &String::from_str(&client.env, "QmDesc"),
+            &self.params,
+        )
+    }
+
+    fn try_create(
+        &self,
+        client: &QuidStoreContractClient,
+        owner: &Address,
+    ) -> Result<Result<u64, Error>, Result<QuidError, InvokeError>> {
+        client.try_create_mission(
+            owner,
+            &String::from_str(&client.env, "Mission"),
+            &String::from_str(&client.env, "QmDesc"),
+            &self.params,
+        )
+    }
+}
+
+fn create_basic_mission(
+    env: &Env,
+    client: &QuidStoreContractClient,
+    owner: &Address,
+    token_address: &Address,
+    slots: u32,
+) -> u64 {
+    MissionBuilder::new(env, token_address)
+        .slots(slots)
+        .create(client, owner)
+}
+
 #[test]
 fn test_happy_path_create_submit_payout() {
     let (env, contract_id, owner, token_address) = setup_test_env();
@@ -37,30 +194,19 @@ fn test_happy_path_create_submit_payout() {
 
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Happy Path"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(reward_amount)
+        .stake_amount(stake)
+        .create(&client, &owner);
 
     let cid = String::from_str(&env, "QmSubmission");
-    client.submit_feedback(&mission_id, &hunter, &cid, &token_address, &stake);
+    client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
 
     let balance_before = token_client.balance(&hunter);
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    // Payouts are pulled by the hunter, never pushed
+    assert_eq!(token_client.balance(&hunter), balance_before);
+    client.claim_reward(&mission_id, &hunter);
 
     let balance_after = token_client.balance(&hunter);
     // Hunter receives reward + stake refund
@@ -79,28 +225,11 @@ fn test_prevent_double_submission() {
 
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Double Sub Test"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
 
     let cid = String::from_str(&env, "QmFirst");
-    client.submit_feedback(&mission_id, &hunter, &cid, &token_address, &10);
-    client.submit_feedback(&mission_id, &hunter, &cid, &token_address, &10);
+    client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
 }
 
 #[test]
@@ -113,29 +242,15 @@ fn test_cancel_mission_refund() {
     let slots: u32 = 10;
     let total_deposit = reward_amount * (slots as i128);
 
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Refund Test"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &slots,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(reward_amount)
+        .slots(slots)
+        .create(&client, &owner);
 
     let contract_balance = token_client.balance(&contract_id);
     assert_eq!(contract_balance, total_deposit);
 
-    client.cancel_mission(&mission_id);
+    client.cancel_mission(&mission_id, &owner);
 
     let contract_balance_after = token_client.balance(&contract_id);
     assert_eq!(contract_balance_after, 0);
@@ -150,24 +265,9 @@ fn test_mission_capacity_limit() {
     let (env, contract_id, owner, token_address) = setup_test_env();
     let client = QuidStoreContractClient::new(&env, &contract_id);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Full Test"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &1,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .slots(1)
+        .create(&client, &owner);
 
     let hunter1 = Address::generate(&env);
     let hunter2 = Address::generate(&env);
@@ -175,11 +275,13 @@ fn test_mission_capacity_limit() {
     mint_tokens_for_hunter(&env, &token_address, &hunter2, 1000);
     let cid = String::from_str(&env, "QmVal");
 
-    client.submit_feedback(&mission_id, &hunter1, &cid, &token_address, &10);
-    client.submit_feedback(&mission_id, &hunter2, &cid, &token_address, &10);
+    client.submit_feedback(&mission_id, &hunter1, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &hunter2, &cid, &Vec::new(&env));
 
-    client.payout_participant(&mission_id, &hunter1);
-    client.payout_participant(&mission_id, &hunter2);
+    client.payout_participant(&mission_id, &owner, &hunter1, &None);
+    client.claim_reward(&mission_id, &hunter1);
+    client.payout_participant(&mission_id, &owner, &hunter2, &None);
+    client.claim_reward(&mission_id, &hunter2);
 }
 
 #[test]
@@ -191,34 +293,18 @@ fn test_cannot_payout_twice() {
 
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Double Pay"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
 
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "Qm"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
-    client.payout_participant(&mission_id, &hunter);
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
 }
 
 #[test]
@@ -240,8 +326,7 @@ fn test_submit_feedback_mission_not_found() {
         &999,
         &hunter,
         &String::from_str(&env, "Qm"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
 }
 
@@ -251,24 +336,9 @@ fn test_create_mission_negative_reward() {
     let (env, contract_id, owner, token_address) = setup_test_env();
     let client = QuidStoreContractClient::new(&env, &contract_id);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 0,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let _ = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Bad Reward"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let _ = MissionBuilder::new(&env, &token_address)
+        .reward(0)
+        .create(&client, &owner);
 }
 
 #[test]
@@ -280,31 +350,13 @@ fn test_submit_feedback_when_paused() {
 
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Pause Test"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
-    client.pause_mission(&mission_id);
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
+    client.pause_mission(&mission_id, &owner);
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "Qm"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
 }
 
@@ -317,24 +369,10 @@ fn test_cancel_mission_partial_payouts_refund() {
     let reward_amount: i128 = 100;
     let slots: u32 = 3;
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Partial Refund"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &slots,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(reward_amount)
+        .slots(slots)
+        .create(&client, &owner);
 
     let hunter = Address::generate(&env);
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
@@ -342,13 +380,13 @@ fn test_cancel_mission_partial_payouts_refund() {
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSub"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
 
     let owner_balance_before_cancel = token_client.balance(&owner);
-    client.cancel_mission(&mission_id);
+    client.cancel_mission(&mission_id, &owner);
     let owner_balance_after_cancel = token_client.balance(&owner);
 
     let expected_refund = (slots - 1) as i128 * reward_amount;
@@ -372,25 +410,9 @@ fn test_payout_without_submission() {
     let client = QuidStoreContractClient::new(&env, &contract_id);
     let hunter = Address::generate(&env);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "No Sub"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
-    client.payout_participant(&mission_id, &hunter);
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
 }
 
 #[test]
@@ -403,24 +425,9 @@ fn test_stake_deducted_on_submission() {
     let stake_amount = 50;
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Stake Test"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_amount(stake_amount)
+        .create(&client, &owner);
 
     let hunter_balance_before = token_client.balance(&hunter);
     let contract_balance_before = token_client.balance(&contract_id);
@@ -429,8 +436,7 @@ fn test_stake_deducted_on_submission() {
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &stake_amount,
+        &Vec::new(&env),
     );
 
     let hunter_balance_after = token_client.balance(&hunter);
@@ -448,36 +454,10 @@ fn test_stake_deducted_on_submission() {
 fn test_stake_invalid_amount_zero() {
     let (env, contract_id, owner, token_address) = setup_test_env();
     let client = QuidStoreContractClient::new(&env, &contract_id);
-    let hunter = Address::generate(&env);
-
-    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
-
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
 
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Invalid Stake"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
-
-    client.submit_feedback(
-        &mission_id,
-        &hunter,
-        &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &0,
-    );
+    MissionBuilder::new(&env, &token_address)
+        .stake_amount(0)
+        .create(&client, &owner);
 }
 
 #[test]
@@ -485,36 +465,10 @@ fn test_stake_invalid_amount_zero() {
 fn test_stake_invalid_amount_negative() {
     let (env, contract_id, owner, token_address) = setup_test_env();
     let client = QuidStoreContractClient::new(&env, &contract_id);
-    let hunter = Address::generate(&env);
-
-    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
 
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Negative Stake"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
-
-    client.submit_feedback(
-        &mission_id,
-        &hunter,
-        &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &-10,
-    );
+    MissionBuilder::new(&env, &token_address)
+        .stake_amount(-10)
+        .create(&client, &owner);
 }
 
 #[test]
@@ -525,37 +479,21 @@ fn test_update_submission_success() {
 
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Update Test"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
 
     let original_cid = String::from_str(&env, "QmOriginal");
-    client.submit_feedback(&mission_id, &hunter, &original_cid, &token_address, &10);
+    client.submit_feedback(&mission_id, &hunter, &original_cid, &Vec::new(&env));
 
     let new_cid = String::from_str(&env, "QmUpdated");
     client.update_submission(&mission_id, &hunter, &new_cid);
 
     // Verify submission was updated (by trying to payout - should work)
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #9)")]
+#[should_panic(expected = "Error(Contract, #12)")]
 fn test_update_submission_after_payout() {
     let (env, contract_id, owner, token_address) = setup_test_env();
     let client = QuidStoreContractClient::new(&env, &contract_id);
@@ -563,38 +501,53 @@ fn test_update_submission_after_payout() {
 
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Update After Pay"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
 
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmFirst"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
 
-    // Should fail: already paid
+    // Should fail: approved, even though the reward is still unclaimed
     client.update_submission(&mission_id, &hunter, &String::from_str(&env, "QmNew"));
 }
 
+#[test]
+fn test_update_submission_after_reject_or_slash() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    client.set_treasury(&Address::generate(&env));
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let rejected = Address::generate(&env);
+    let slashed = Address::generate(&env);
+    for hunter in [&rejected, &slashed] {
+        mint_tokens_for_hunter(&env, &token_address, hunter, 1000);
+        client.submit_feedback(
+            &mission_id,
+            hunter,
+            &String::from_str(&env, "QmFirst"),
+            &Vec::new(&env),
+        );
+    }
+
+    let reason = String::from_str(&env, "QmReason");
+    client.reject_submission(&mission_id, &owner, &rejected, &reason);
+    client.slash_hunter_stake(&mission_id, &owner, &slashed, &reason);
+
+    // A slashed hunter cannot swap the judged CID before appealing
+    let new_cid = String::from_str(&env, "QmNew");
+    for hunter in [&rejected, &slashed] {
+        let result = client.try_update_submission(&mission_id, hunter, &new_cid);
+        assert_eq!(result, Err(Ok(QuidError::NotPending)));
+        let submission = client.get_submission(&mission_id, hunter);
+        assert_eq!(submission.ipfs_cid, String::from_str(&env, "QmFirst"));
+    }
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #11)")]
 fn test_update_submission_not_found() {
@@ -602,24 +555,7 @@ fn test_update_submission_not_found() {
     let client = QuidStoreContractClient::new(&env, &contract_id);
     let hunter = Address::generate(&env);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "No Sub Update"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
 
     // Try to update without submitting first
     client.update_submission(&mission_id, &hunter, &String::from_str(&env, "QmNew"));
@@ -634,33 +570,15 @@ fn test_update_submission_mission_not_open() {
 
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Paused Update"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
 
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmFirst"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
-    client.pause_mission(&mission_id);
+    client.pause_mission(&mission_id, &owner);
 
     // Should fail: mission is paused
     client.update_submission(&mission_id, &hunter, &String::from_str(&env, "QmNew"));
@@ -674,28 +592,28 @@ fn test_create_mission_with_asset_gating() {
     let gating_token = Address::generate(&env);
     let min_amount: i128 = 1000;
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: Some(gating_token.clone()),
-        min_asset_amount: min_amount,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Gated Mission"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .requirements(Vec::from_array(
+            &env,
+            [AssetRequirement {
+                token: gating_token.clone(),
+                min_amount,
+            }],
+        ))
+        .create(&client, &owner);
 
     let mission = client.get_mission(&mission_id);
-    assert_eq!(mission.min_asset, Some(gating_token));
-    assert_eq!(mission.min_asset_amount, min_amount);
+    assert_eq!(
+        mission.asset_requirements,
+        Vec::from_array(
+            &env,
+            [AssetRequirement {
+                token: gating_token,
+                min_amount,
+            }]
+        )
+    );
+    assert_eq!(mission.asset_rule, GateRule::All);
 }
 
 #[test]
@@ -703,28 +621,10 @@ fn test_create_mission_without_asset_gating() {
     let (env, contract_id, owner, token_address) = setup_test_env();
     let client = QuidStoreContractClient::new(&env, &contract_id);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "No Gate"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
 
     let mission = client.get_mission(&mission_id);
-    assert_eq!(mission.min_asset, None);
-    assert_eq!(mission.min_asset_amount, 0);
+    assert!(mission.asset_requirements.is_empty());
 }
 
 #[test]
@@ -735,24 +635,15 @@ fn test_create_mission_with_zero_asset_amount() {
 
     let gating_token = Address::generate(&env);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: Some(gating_token),
-        min_asset_amount: 0,
-    };
-
-    let _ = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Invalid Gate"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let _ = MissionBuilder::new(&env, &token_address)
+        .requirements(Vec::from_array(
+            &env,
+            [AssetRequirement {
+                token: gating_token,
+                min_amount: 0,
+            }],
+        ))
+        .create(&client, &owner);
 }
 
 #[test]
@@ -763,24 +654,15 @@ fn test_create_mission_with_negative_asset_amount() {
 
     let gating_token = Address::generate(&env);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: Some(gating_token),
-        min_asset_amount: -100,
-    };
-
-    let _ = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Negative Gate"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let _ = MissionBuilder::new(&env, &token_address)
+        .requirements(Vec::from_array(
+            &env,
+            [AssetRequirement {
+                token: gating_token,
+                min_amount: -100,
+            }],
+        ))
+        .create(&client, &owner);
 }
 
 #[test]
@@ -806,24 +688,12 @@ fn test_full_lifecycle_integration() {
     let contract_balance_initial = token_client.balance(&contract_id);
 
     // Step 1: Create mission
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount,
-    };
 
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Full Lifecycle Test"),
-        &String::from_str(&env, "QmTestDescription"),
-        &reward,
-        &max_participants,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(reward_amount)
+        .slots(max_participants)
+        .stake_amount(stake_amount)
+        .create(&client, &owner);
 
     // Verify mission was created
     let mission = client.get_mission(&mission_id);
@@ -850,13 +720,7 @@ fn test_full_lifecycle_integration() {
 
     // Step 2: Submit work
     let submission_cid = String::from_str(&env, "QmSubmissionHash");
-    client.submit_feedback(
-        &mission_id,
-        &hunter,
-        &submission_cid,
-        &token_address,
-        &stake_amount,
-    );
+    client.submit_feedback(&mission_id, &hunter, &submission_cid, &Vec::new(&env));
 
     // Verify balances after submission (stake deducted)
     let hunter_balance_after_submit = token_client.balance(&hunter);
@@ -872,7 +736,8 @@ fn test_full_lifecycle_integration() {
     );
 
     // Step 3: Payout participant
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
 
     // Verify final balances
     let hunter_balance_final = token_client.balance(&hunter);
@@ -929,35 +794,30 @@ fn test_slash_stake_sends_to_treasury() {
     let stake_amount: i128 = 50;
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Slash Test"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_amount(stake_amount)
+        .create(&client, &owner);
 
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSpam"),
-        &token_address,
-        &stake_amount,
+        &Vec::new(&env),
     );
 
     let treasury_balance_before = token_client.balance(&treasury);
 
-    client.slash_hunter_stake(&mission_id, &hunter, &token_address);
+    client.slash_hunter_stake(
+        &mission_id,
+        &owner,
+        &hunter,
+        &String::from_str(&env, "QmReason"),
+    );
+
+    // Held during the dispute window
+    assert_eq!(token_client.balance(&treasury), treasury_balance_before);
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    client.finalize_slash(&mission_id, &hunter);
 
     let treasury_balance_after = token_client.balance(&treasury);
     assert_eq!(
@@ -977,26 +837,15 @@ fn test_slash_stake_not_found() {
 
     let hunter = Address::generate(&env);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
 
-    let mission_id = client.create_mission(
+    // No submission — stake doesn't exist
+    client.slash_hunter_stake(
+        &mission_id,
         &owner,
-        &String::from_str(&env, "No Stake"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
+        &hunter,
+        &String::from_str(&env, "QmReason"),
     );
-
-    // No submission — stake doesn't exist
-    client.slash_hunter_stake(&mission_id, &hunter, &token_address);
 }
 
 #[test]
@@ -1008,34 +857,24 @@ fn test_slash_stake_treasury_not_set() {
     let hunter = Address::generate(&env);
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "No Treasury"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_amount(50)
+        .create(&client, &owner);
 
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSpam"),
-        &token_address,
-        &50,
+        &Vec::new(&env),
     );
 
     // Treasury not set — should fail
-    client.slash_hunter_stake(&mission_id, &hunter, &token_address);
+    client.slash_hunter_stake(
+        &mission_id,
+        &owner,
+        &hunter,
+        &String::from_str(&env, "QmReason"),
+    );
 }
 
 #[test]
@@ -1049,36 +888,31 @@ fn test_slash_stake_removes_storage_key() {
     let hunter = Address::generate(&env);
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Key Removal"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_amount(50)
+        .create(&client, &owner);
 
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSpam"),
-        &token_address,
-        &50,
+        &Vec::new(&env),
     );
 
-    client.slash_hunter_stake(&mission_id, &hunter, &token_address);
+    client.slash_hunter_stake(
+        &mission_id,
+        &owner,
+        &hunter,
+        &String::from_str(&env, "QmReason"),
+    );
 
     // Slashing again should fail because the key was removed
-    let result = client.try_slash_hunter_stake(&mission_id, &hunter, &token_address);
+    let result = client.try_slash_hunter_stake(
+        &mission_id,
+        &owner,
+        &hunter,
+        &String::from_str(&env, "QmReason"),
+    );
     assert!(result.is_err());
 }
 
@@ -1092,38 +926,24 @@ fn test_refund_stake_success() {
     let stake_amount: i128 = 50;
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Refund Test"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_amount(stake_amount)
+        .create(&client, &owner);
 
     // Submit feedback with stake
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &stake_amount,
+        &Vec::new(&env),
     );
 
     let hunter_balance_after_submit = token_client.balance(&hunter);
     let contract_balance_after_submit = token_client.balance(&contract_id);
 
     // Call refund_stake (via payout which should call it internally)
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
 
     // After payout, hunter should have reward + stake refund
     let hunter_balance_after_payout = token_client.balance(&hunter);
@@ -1132,18 +952,18 @@ fn test_refund_stake_success() {
     // Hunter gets reward + stake back: balance_after_submit + reward_amount + stake_amount
     assert_eq!(
         hunter_balance_after_payout,
-        hunter_balance_after_submit + reward.reward_amount + stake_amount
+        hunter_balance_after_submit + 100 + stake_amount
     );
 
     // Contract should have lost both reward and stake
     assert_eq!(
         contract_balance_after_payout,
-        contract_balance_after_submit - reward.reward_amount - stake_amount
+        contract_balance_after_submit - 100 - stake_amount
     );
 }
 
 #[test]
-fn test_refund_stake_no_stake_exists() {
+fn test_slashed_submission_cannot_be_paid() {
     let (env, contract_id, owner, token_address) = setup_test_env();
     let client = QuidStoreContractClient::new(&env, &contract_id);
     let token_client = TokenClient::new(&env, &token_address);
@@ -1151,52 +971,38 @@ fn test_refund_stake_no_stake_exists() {
     let hunter = Address::generate(&env);
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "No Stake Refund"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_amount(50)
+        .create(&client, &owner);
 
-    // Submit without stake by first slashing it
-    let stake_amount: i128 = 50;
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &stake_amount,
+        &Vec::new(&env),
     );
 
     // Set treasury and slash the stake (removes it from storage)
     let treasury = Address::generate(&env);
     client.set_treasury(&treasury);
-    client.slash_hunter_stake(&mission_id, &hunter, &token_address);
+    client.slash_hunter_stake(
+        &mission_id,
+        &owner,
+        &hunter,
+        &String::from_str(&env, "QmReason"),
+    );
 
     let hunter_balance_before = token_client.balance(&hunter);
-
-    // Now payout - refund_stake should handle missing stake gracefully
-    // Should not panic, just skip the refund and pay the reward
-    client.payout_participant(&mission_id, &hunter);
-
-    let hunter_balance_after = token_client.balance(&hunter);
-
-    // Hunter should only receive reward (no stake to refund)
     assert_eq!(
-        hunter_balance_after,
-        hunter_balance_before + reward.reward_amount
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Slashed
     );
+
+    // A slashed submission is closed and can no longer be rewarded
+    let result = client.try_payout_participant(&mission_id, &owner, &hunter, &None);
+    assert_eq!(result, Err(Ok(QuidError::NotPending)));
+    assert_eq!(token_client.balance(&hunter), hunter_balance_before);
+    assert_eq!(client.get_open_submission_count(&mission_id), 0);
 }
 
 #[test]
@@ -1209,37 +1015,23 @@ fn test_refund_stake_removes_storage_key() {
     let stake_amount: i128 = 50;
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Key Removal Test"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_amount(stake_amount)
+        .create(&client, &owner);
 
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &stake_amount,
+        &Vec::new(&env),
     );
 
     let hunter_balance_before = token_client.balance(&hunter);
     let contract_balance_before = token_client.balance(&contract_id);
 
     // Payout should refund stake and pay reward
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
 
     let hunter_balance_after = token_client.balance(&hunter);
     let contract_balance_after = token_client.balance(&contract_id);
@@ -1247,17 +1039,17 @@ fn test_refund_stake_removes_storage_key() {
     // Verify hunter received reward + stake
     assert_eq!(
         hunter_balance_after,
-        hunter_balance_before + reward.reward_amount + stake_amount
+        hunter_balance_before + 100 + stake_amount
     );
 
     // Verify contract balance decreased by reward + stake
     assert_eq!(
         contract_balance_after,
-        contract_balance_before - reward.reward_amount - stake_amount
+        contract_balance_before - 100 - stake_amount
     );
 
     // Storage key should be removed - attempting to payout again should fail
-    let result = client.try_payout_participant(&mission_id, &hunter);
+    let result = client.try_payout_participant(&mission_id, &owner, &hunter, &None);
     assert!(result.is_err());
 }
 
@@ -1271,23 +1063,9 @@ fn test_refund_stake_correct_amount() {
     let stake_amount: i128 = 75;
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Amount Test"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_amount(stake_amount)
+        .create(&client, &owner);
 
     let hunter_balance_initial = token_client.balance(&hunter);
 
@@ -1295,8 +1073,7 @@ fn test_refund_stake_correct_amount() {
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &stake_amount,
+        &Vec::new(&env),
     );
 
     let hunter_balance_after_submit = token_client.balance(&hunter);
@@ -1318,53 +1095,38 @@ fn test_refund_stake_multiple_hunters() {
 
     let hunter1 = Address::generate(&env);
     let hunter2 = Address::generate(&env);
-    let stake_amount_1: i128 = 30;
-    let stake_amount_2: i128 = 50;
+    let stake_amount: i128 = 30;
 
     mint_tokens_for_hunter(&env, &token_address, &hunter1, 1000);
     mint_tokens_for_hunter(&env, &token_address, &hunter2, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Multi Hunter"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_amount(stake_amount)
+        .create(&client, &owner);
 
-    // Both hunters submit with different stakes
+    // Both hunters submit with the mission-wide stake
     client.submit_feedback(
         &mission_id,
         &hunter1,
         &String::from_str(&env, "QmSub1"),
-        &token_address,
-        &stake_amount_1,
+        &Vec::new(&env),
     );
 
     client.submit_feedback(
         &mission_id,
         &hunter2,
         &String::from_str(&env, "QmSub2"),
-        &token_address,
-        &stake_amount_2,
+        &Vec::new(&env),
     );
 
     let hunter1_balance_after_submit = token_client.balance(&hunter1);
     let hunter2_balance_after_submit = token_client.balance(&hunter2);
 
     // Payout both hunters
-    client.payout_participant(&mission_id, &hunter1);
-    client.payout_participant(&mission_id, &hunter2);
+    client.payout_participant(&mission_id, &owner, &hunter1, &None);
+    client.claim_reward(&mission_id, &hunter1);
+    client.payout_participant(&mission_id, &owner, &hunter2, &None);
+    client.claim_reward(&mission_id, &hunter2);
 
     // Each hunter should get their reward + their stake back
     let hunter1_balance_final = token_client.balance(&hunter1);
@@ -1372,11 +1134,11 @@ fn test_refund_stake_multiple_hunters() {
 
     assert_eq!(
         hunter1_balance_final,
-        hunter1_balance_after_submit + reward.reward_amount + stake_amount_1
+        hunter1_balance_after_submit + 100 + stake_amount
     );
     assert_eq!(
         hunter2_balance_final,
-        hunter2_balance_after_submit + reward.reward_amount + stake_amount_2
+        hunter2_balance_after_submit + 100 + stake_amount
     );
 }
 
@@ -1390,53 +1152,39 @@ fn test_refund_stake_prevents_double_refund() {
     let stake_amount: i128 = 50;
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Double Refund Test"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_amount(stake_amount)
+        .create(&client, &owner);
 
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &stake_amount,
+        &Vec::new(&env),
     );
 
     let hunter_balance_before_payout = token_client.balance(&hunter);
 
     // First payout - should refund stake
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
 
     let hunter_balance_after_payout = token_client.balance(&hunter);
 
     // Verify hunter received reward + stake
     assert_eq!(
         hunter_balance_after_payout,
-        hunter_balance_before_payout + reward.reward_amount + stake_amount
+        hunter_balance_before_payout + 100 + stake_amount
     );
 
     // Attempting to payout again should fail (already paid)
     // This prevents double refund of the stake
-    let result = client.try_payout_participant(&mission_id, &hunter);
+    let result = client.try_payout_participant(&mission_id, &owner, &hunter, &None);
     assert!(result.is_err());
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #16)")]
+#[should_panic(expected = "Error(Contract, #34)")]
 fn test_asset_gating_insufficient_balance() {
     let (env, contract_id, owner, token_address) = setup_test_env();
     let client = QuidStoreContractClient::new(&env, &contract_id);
@@ -1452,32 +1200,22 @@ fn test_asset_gating_insufficient_balance() {
     // Hunter has NO balance of the gating token
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000); // Only stake tokens
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: Some(gating_token_address.clone()),
-        min_asset_amount,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Gated Mission"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .requirements(Vec::from_array(
+            &env,
+            [AssetRequirement {
+                token: gating_token_address.clone(),
+                min_amount: min_asset_amount,
+            }],
+        ))
+        .create(&client, &owner);
 
     // Should fail: hunter has 0 balance of gating token
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
 }
 
@@ -1501,36 +1239,27 @@ fn test_asset_gating_sufficient_balance() {
     // Also mint stake tokens
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: Some(gating_token_address.clone()),
-        min_asset_amount,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Gated Mission"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .requirements(Vec::from_array(
+            &env,
+            [AssetRequirement {
+                token: gating_token_address.clone(),
+                min_amount: min_asset_amount,
+            }],
+        ))
+        .create(&client, &owner);
 
     // Should succeed: hunter has exactly the required balance
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
 
     // Verify submission was created
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
 }
 
 #[test]
@@ -1553,40 +1282,31 @@ fn test_asset_gating_more_than_required() {
     // Also mint stake tokens
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: Some(gating_token_address.clone()),
-        min_asset_amount,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Gated Mission"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .requirements(Vec::from_array(
+            &env,
+            [AssetRequirement {
+                token: gating_token_address.clone(),
+                min_amount: min_asset_amount,
+            }],
+        ))
+        .create(&client, &owner);
 
     // Should succeed: hunter has more than required balance
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
 
     // Verify submission was created
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #16)")]
+#[should_panic(expected = "Error(Contract, #34)")]
 fn test_asset_gating_just_below_required() {
     let (env, contract_id, owner, token_address) = setup_test_env();
     let client = QuidStoreContractClient::new(&env, &contract_id);
@@ -1606,32 +1326,22 @@ fn test_asset_gating_just_below_required() {
     // Also mint stake tokens
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: Some(gating_token_address.clone()),
-        min_asset_amount,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Gated Mission"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .requirements(Vec::from_array(
+            &env,
+            [AssetRequirement {
+                token: gating_token_address.clone(),
+                min_amount: min_asset_amount,
+            }],
+        ))
+        .create(&client, &owner);
 
     // Should fail: hunter has 999 but needs 1000
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
 }
 
@@ -1645,37 +1355,21 @@ fn test_no_asset_gating_allows_any_hunter() {
     // Hunter only has stake tokens, no gating token
     mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
     // No asset gating
-    let min_asset = MinAsset {
-        min_asset_token: None,
-        min_asset_amount: 0,
-    };
 
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Open Mission"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
 
     // Should succeed: no gating requirement
     client.submit_feedback(
         &mission_id,
         &hunter,
         &String::from_str(&env, "QmSubmission"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
 
     // Verify submission was created
-    client.payout_participant(&mission_id, &hunter);
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
 }
 
 #[test]
@@ -1701,43 +1395,4174 @@ fn test_asset_gating_multiple_hunters_different_balances() {
     gating_token_admin_client.mint(&hunter2, &1000);
     mint_tokens_for_hunter(&env, &token_address, &hunter2, 1000);
 
-    let reward = Reward {
-        reward_token: token_address.clone(),
-        reward_amount: 100,
-    };
-
-    let min_asset = MinAsset {
-        min_asset_token: Some(gating_token_address.clone()),
-        min_asset_amount,
-    };
-
-    let mission_id = client.create_mission(
-        &owner,
-        &String::from_str(&env, "Gated Mission"),
-        &String::from_str(&env, "QmDesc"),
-        &reward,
-        &5,
-        &min_asset,
-    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .requirements(Vec::from_array(
+            &env,
+            [AssetRequirement {
+                token: gating_token_address.clone(),
+                min_amount: min_asset_amount,
+            }],
+        ))
+        .create(&client, &owner);
 
     // Both hunters should succeed
     client.submit_feedback(
         &mission_id,
         &hunter1,
         &String::from_str(&env, "QmSubmission1"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
 
     client.submit_feedback(
         &mission_id,
         &hunter2,
         &String::from_str(&env, "QmSubmission2"),
-        &token_address,
-        &10,
+        &Vec::new(&env),
     );
 
     // Verify both submissions were created
-    client.payout_participant(&mission_id, &hunter1);
-    client.payout_participant(&mission_id, &hunter2);
+    client.payout_participant(&mission_id, &owner, &hunter1, &None);
+    client.claim_reward(&mission_id, &hunter1);
+    client.payout_participant(&mission_id, &owner, &hunter2, &None);
+    client.claim_reward(&mission_id, &hunter2);
+}
+
+#[test]
+fn test_reject_submission_refunds_stake() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let hunter = Address::generate(&env);
+    let stake_amount: i128 = 25;
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_amount(stake_amount)
+        .create(&client, &owner);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSubmission"),
+        &Vec::new(&env),
+    );
+
+    let hunter_balance_after_submit = token_client.balance(&hunter);
+    let contract_balance_after_submit = token_client.balance(&contract_id);
+
+    client.reject_submission(
+        &mission_id,
+        &owner,
+        &hunter,
+        &String::from_str(&env, "QmReason"),
+    );
+
+    assert_eq!(
+        token_client.balance(&hunter),
+        hunter_balance_after_submit + stake_amount
+    );
+    assert_eq!(
+        token_client.balance(&contract_id),
+        contract_balance_after_submit - stake_amount
+    );
+
+    let submission = client.get_submission(&mission_id, &hunter);
+    assert_eq!(submission.status, SubmissionStatus::Rejected);
+
+    // Rejection leaves the reward slot available
+    let mission = client.get_mission(&mission_id);
+    assert_eq!(mission.participants_count, 0);
+    assert_eq!(mission.status, MissionStatus::Open);
+}
+
+#[test]
+fn test_reject_submission_emits_event() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSubmission"),
+        &Vec::new(&env),
+    );
+
+    client.reject_submission(
+        &mission_id,
+        &owner,
+        &hunter,
+        &String::from_str(&env, "QmReason"),
+    );
+
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    assert_eq!(event.0, contract_id);
+    assert_eq!(
+        event.1,
+        (symbol_short!("sub"), Symbol::new(&env, "rejected")).into_val(&env)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_reject_submission_twice() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSubmission"),
+        &Vec::new(&env),
+    );
+
+    let reason = String::from_str(&env, "QmReason");
+    client.reject_submission(&mission_id, &owner, &hunter, &reason);
+    client.reject_submission(&mission_id, &owner, &hunter, &reason);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_payout_after_reject() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSubmission"),
+        &Vec::new(&env),
+    );
+
+    client.reject_submission(
+        &mission_id,
+        &owner,
+        &hunter,
+        &String::from_str(&env, "QmReason"),
+    );
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+}
+
+#[test]
+fn test_reject_submission_after_mission_completed() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let winner = Address::generate(&env);
+    let loser = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &winner, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &loser, 1000);
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .slots(1)
+        .create(&client, &owner);
+
+    let cid = String::from_str(&env, "QmSubmission");
+    client.submit_feedback(&mission_id, &winner, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &loser, &cid, &Vec::new(&env));
+
+    client.payout_participant(&mission_id, &owner, &winner, &None);
+    client.claim_reward(&mission_id, &winner);
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Completed
+    );
+
+    // The losing hunter can still be rejected and get their stake back
+    client.reject_submission(
+        &mission_id,
+        &owner,
+        &loser,
+        &String::from_str(&env, "QmReason"),
+    );
+    assert_eq!(token_client.balance(&loser), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_reject_submission_not_found() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let hunter = Address::generate(&env);
+
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
+
+    client.reject_submission(
+        &mission_id,
+        &owner,
+        &hunter,
+        &String::from_str(&env, "QmReason"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_zero_stake_policy_with_amount() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    MissionBuilder::new(&env, &token_address)
+        .stake_token(None)
+        .stake_amount(10)
+        .create(&client, &owner);
+}
+
+#[test]
+fn test_zero_stake_mission_submit_and_payout() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    // Hunter holds no tokens at all
+    let hunter = Address::generate(&env);
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .no_stake()
+        .create(&client, &owner);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSubmission"),
+        &Vec::new(&env),
+    );
+    assert_eq!(token_client.balance(&contract_id), 500);
+
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&hunter), 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_slash_zero_stake_mission() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+
+    let hunter = Address::generate(&env);
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .no_stake()
+        .create(&client, &owner);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSpam"),
+        &Vec::new(&env),
+    );
+    client.slash_hunter_stake(
+        &mission_id,
+        &owner,
+        &hunter,
+        &String::from_str(&env, "QmReason"),
+    );
+}
+
+#[test]
+fn test_stake_token_differs_from_reward_token() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let reward_client = TokenClient::new(&env, &token_address);
+
+    let stake_admin = Address::generate(&env);
+    let stake_address = env
+        .register_stellar_asset_contract_v2(stake_admin)
+        .address();
+    let stake_client = TokenClient::new(&env, &stake_address);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &stake_address, &hunter, 1000);
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_token(Some(stake_address.clone()))
+        .stake_amount(40)
+        .create(&client, &owner);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSubmission"),
+        &Vec::new(&env),
+    );
+    assert_eq!(stake_client.balance(&hunter), 960);
+    assert_eq!(stake_client.balance(&contract_id), 40);
+
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+
+    // Reward paid in the reward token, stake returned in the stake token
+    assert_eq!(reward_client.balance(&hunter), 100);
+    assert_eq!(stake_client.balance(&hunter), 1000);
+    assert_eq!(stake_client.balance(&contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+fn test_create_mission_deadline_in_past() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    let schedule = Schedule {
+        submission_deadline: 10_000,
+        review_deadline: 20_000,
+        review_window: DAY,
+        auto_approve: false,
+        reveal_deadline: 0,
+    };
+
+    MissionBuilder::new(&env, &token_address)
+        .schedule(schedule)
+        .create(&client, &owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+fn test_create_mission_review_before_submission_deadline() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let schedule = Schedule {
+        submission_deadline: 2 * WEEK,
+        review_deadline: WEEK,
+        review_window: DAY,
+        auto_approve: false,
+        reveal_deadline: 0,
+    };
+
+    MissionBuilder::new(&env, &token_address)
+        .schedule(schedule)
+        .create(&client, &owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_submit_feedback_after_deadline() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
+
+    env.ledger().with_mut(|li| li.timestamp = WEEK + 1);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmLate"),
+        &Vec::new(&env),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+fn test_expire_mission_before_review_deadline() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
+
+    env.ledger().with_mut(|li| li.timestamp = 2 * WEEK);
+
+    client.expire_mission(&mission_id);
+}
+
+#[test]
+fn test_expire_mission_refunds_slots_and_stakes() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let winner = Address::generate(&env);
+    let pending = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &winner, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &pending, 1000);
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .slots(3)
+        .create(&client, &owner);
+
+    let cid = String::from_str(&env, "QmSubmission");
+    client.submit_feedback(&mission_id, &winner, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &pending, &cid, &Vec::new(&env));
+    client.payout_participant(&mission_id, &owner, &winner, &None);
+    client.claim_reward(&mission_id, &winner);
+
+    let owner_balance_before = token_client.balance(&owner);
+
+    // Founder disappears; anyone can close the mission after review ends
+    env.ledger().with_mut(|li| li.timestamp = 2 * WEEK + 1);
+    client.expire_mission(&mission_id);
+    assert_eq!(token_client.balance(&owner), owner_balance_before + 200);
+    assert_eq!(client.get_open_submission_count(&mission_id), 1);
+
+    assert_eq!(client.refund_pending(&mission_id, &0, &10), 0);
+    assert_eq!(token_client.balance(&pending), 1000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+
+    let mission = client.get_mission(&mission_id);
+    assert_eq!(mission.status, MissionStatus::Expired);
+    assert_eq!(
+        client.get_submission(&mission_id, &pending).status,
+        SubmissionStatus::Expired
+    );
+    assert_eq!(
+        client.get_submission(&mission_id, &winner).status,
+        SubmissionStatus::Paid
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_expire_mission_twice() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .slots(3)
+        .create(&client, &owner);
+
+    env.ledger().with_mut(|li| li.timestamp = 2 * WEEK + 1);
+    client.expire_mission(&mission_id);
+    client.expire_mission(&mission_id);
+}
+
+#[test]
+fn test_expire_completed_mission_does_not_refund_twice() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let other_owner = Address::generate(&env);
+    StellarAssetClient::new(&env, &token_address).mint(&other_owner, &1000);
+    create_basic_mission(&env, &client, &other_owner, &token_address, 3);
+
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+    let owner_balance = token_client.balance(&owner);
+
+    client.update_mission_status(&mission_id, &owner, &MissionStatus::Completed);
+    assert_eq!(token_client.balance(&owner), owner_balance + 300);
+
+    env.ledger().with_mut(|li| li.timestamp = 2 * WEEK + 1);
+    let result = client.try_expire_mission(&mission_id);
+    assert_eq!(result, Err(Ok(QuidError::MissionClosed)));
+    assert_eq!(token_client.balance(&owner), owner_balance + 300);
+    assert_eq!(token_client.balance(&contract_id), 300);
+}
+
+#[test]
+fn test_cancel_mission_refunds_pending_stakes() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .slots(3)
+        .create(&client, &owner);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &Vec::new(&env),
+    );
+    client.cancel_mission(&mission_id, &owner);
+    assert_eq!(token_client.balance(&hunter), 990);
+
+    assert_eq!(client.refund_pending(&mission_id, &0, &10), 0);
+    assert_eq!(token_client.balance(&hunter), 1000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+/// Soroban's per-transaction limits as of protocol 23: ledger entries in the
+/// footprint, entries written, bytes written and contract event size.
+fn assert_fits_in_transaction(env: &Env) {
+    let resources = env.cost_estimate().resources();
+    assert!(resources.memory_read_entries + resources.disk_read_entries <= 100);
+    assert!(resources.write_entries <= 50);
+    assert!(resources.write_bytes <= 132 * 1024);
+    assert!(resources.contract_events_size_bytes <= 16 * 1024);
+}
+
+#[test]
+fn test_refund_pending_pages_through_many_submissions() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    let cid = String::from_str(&env, "QmFeedback");
+    let mut hunters = Vec::new(&env);
+    for _ in 0..25 {
+        let hunter = Address::generate(&env);
+        mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+        client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+        hunters.push_back(hunter);
+    }
+    assert_eq!(client.get_open_submission_count(&mission_id), 25);
+
+    let result = client.try_refund_pending(&mission_id, &0, &10);
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+
+    // Closing costs the same however many hunters entered
+    client.cancel_mission(&mission_id, &owner);
+    assert_fits_in_transaction(&env);
+
+    let mut cursor = 0;
+    loop {
+        let open = client.refund_pending(&mission_id, &cursor, &u32::MAX);
+        assert_fits_in_transaction(&env);
+        cursor += MAX_BATCH_SIZE;
+        if open == 0 {
+            break;
+        }
+    }
+    assert_eq!(cursor, 30);
+    for hunter in hunters.iter() {
+        assert_eq!(token_client.balance(&hunter), 1000);
+    }
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+fn test_claim_unreviewed_within_review_window() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &Vec::new(&env),
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 3 * DAY);
+    client.claim_unreviewed(&mission_id, &hunter);
+}
+
+#[test]
+fn test_claim_unreviewed_refunds_stake() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let mission_id = MissionBuilder::new(&env, &token_address).create(&client, &owner);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &Vec::new(&env),
+    );
+    assert_eq!(token_client.balance(&hunter), 990);
+
+    env.ledger().with_mut(|li| li.timestamp = 3 * DAY + 1);
+    client.claim_unreviewed(&mission_id, &hunter);
+
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("sub"), Symbol::new(&env, "unreviewed")).into_val(&env)
+    );
+
+    assert_eq!(token_client.balance(&hunter), 1000);
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Expired
+    );
+    assert_eq!(client.get_mission(&mission_id).participants_count, 0);
+}
+
+#[test]
+fn test_claim_unreviewed_auto_approve_pays_reward() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+
+    let schedule = Schedule {
+        auto_approve: true,
+        reveal_deadline: 0,
+        ..default_schedule(&env)
+    };
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .slots(1)
+        .schedule(schedule)
+        .create(&client, &owner);
+
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &Vec::new(&env),
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 3 * DAY + 1);
+    client.claim_unreviewed(&mission_id, &hunter);
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Approved
+    );
+    client.claim_reward(&mission_id, &hunter);
+
+    assert_eq!(token_client.balance(&hunter), 1100);
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Paid
+    );
+
+    let mission = client.get_mission(&mission_id);
+    assert_eq!(mission.participants_count, 1);
+    assert_eq!(mission.status, MissionStatus::Completed);
+}
+
+#[test]
+fn test_slash_closes_submission() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let spammer = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &spammer, 1000);
+
+    let schedule = Schedule {
+        auto_approve: true,
+        reveal_deadline: 0,
+        ..default_schedule(&env)
+    };
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .slots(2)
+        .schedule(schedule)
+        .create(&client, &owner);
+    client.submit_feedback(
+        &mission_id,
+        &spammer,
+        &String::from_str(&env, "QmSpam"),
+        &Vec::new(&env),
+    );
+
+    client.set_treasury(&Address::generate(&env));
+    let reason = String::from_str(&env, "QmReason");
+    client.slash_hunter_stake(&mission_id, &owner, &spammer, &reason);
+    assert_eq!(
+        client.get_submission(&mission_id, &spammer).status,
+        SubmissionStatus::Slashed
+    );
+    assert_eq!(client.get_open_submission_count(&mission_id), 0);
+
+    // The slashed submission cannot be slashed again, withdrawn or rewarded
+    let result = client.try_slash_hunter_stake(&mission_id, &owner, &spammer, &reason);
+    assert_eq!(result, Err(Ok(QuidError::StakeNotFound)));
+    let result = client.try_withdraw_submission(&mission_id, &spammer);
+    assert_eq!(result, Err(Ok(QuidError::NotPending)));
+    env.ledger().with_mut(|li| li.timestamp = 3 * DAY + 1);
+    let result = client.try_claim_unreviewed(&mission_id, &spammer);
+    assert_eq!(result, Err(Ok(QuidError::NotPending)));
+    assert_eq!(token_client.balance(&spammer), 990);
+
+    // Nothing is left to review, so the owner can complete the mission
+    client.update_mission_status(&mission_id, &owner, &MissionStatus::Completed);
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Completed
+    );
+    assert_eq!(
+        client.get_slash(&mission_id, &spammer).status,
+        SlashStatus::Pending
+    );
+}
+
+#[test]
+fn test_claim_unreviewed_auto_approve_mission_full() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let winner = Address::generate(&env);
+    let late = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &winner, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &late, 1000);
+
+    let schedule = Schedule {
+        auto_approve: true,
+        reveal_deadline: 0,
+        ..default_schedule(&env)
+    };
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .slots(1)
+        .schedule(schedule)
+        .create(&client, &owner);
+
+    let cid = String::from_str(&env, "QmSub");
+    client.submit_feedback(&mission_id, &winner, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &late, &cid, &Vec::new(&env));
+    client.payout_participant(&mission_id, &owner, &winner, &None);
+    client.claim_reward(&mission_id, &winner);
+
+    // No reward slots remain, so only the stake comes back
+    env.ledger().with_mut(|li| li.timestamp = 3 * DAY + 1);
+    client.claim_unreviewed(&mission_id, &late);
+
+    assert_eq!(token_client.balance(&late), 1000);
+    assert_eq!(
+        client.get_submission(&mission_id, &late).status,
+        SubmissionStatus::Expired
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+fn test_create_mission_zero_review_window() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let schedule = Schedule {
+        review_window: 0,
+        ..default_schedule(&env)
+    };
+
+    MissionBuilder::new(&env, &token_address)
+        .schedule(schedule)
+        .create(&client, &owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_cannot_reopen_cancelled_mission() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    client.cancel_mission(&mission_id, &owner);
+    client.update_mission_status(&mission_id, &owner, &MissionStatus::Open);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+fn test_cannot_complete_with_pending_submissions() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &Vec::new(&env),
+    );
+
+    client.update_mission_status(&mission_id, &owner, &MissionStatus::Completed);
+}
+
+#[test]
+fn test_complete_mission_refunds_unused_slots() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &Vec::new(&env),
+    );
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+
+    let owner_balance_before = token_client.balance(&owner);
+    client.update_mission_status(&mission_id, &owner, &MissionStatus::Completed);
+
+    assert_eq!(token_client.balance(&owner), owner_balance_before + 200);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Completed
+    );
+}
+
+#[test]
+fn test_pause_and_resume_mission() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    client.pause_mission(&mission_id, &owner);
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Paused
+    );
+
+    client.resume_mission(&mission_id, &owner);
+    assert_eq!(client.get_mission(&mission_id).status, MissionStatus::Open);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &Vec::new(&env),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+fn test_resume_mission_not_paused() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    client.resume_mission(&mission_id, &owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+fn test_pause_mission_twice() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    client.pause_mission(&mission_id, &owner);
+    client.pause_mission(&mission_id, &owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_pause_cancelled_mission() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    client.cancel_mission(&mission_id, &owner);
+    client.pause_mission(&mission_id, &owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+fn test_update_status_to_expired() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    client.update_mission_status(&mission_id, &owner, &MissionStatus::Expired);
+}
+
+#[test]
+fn test_update_status_cancelled_refunds_escrow() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    client.update_mission_status(&mission_id, &owner, &MissionStatus::Cancelled);
+
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_status_change_emits_event() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    client.update_mission_status(&mission_id, &owner, &MissionStatus::Started);
+
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    assert_eq!(event.0, contract_id);
+    assert_eq!(
+        event.1,
+        (symbol_short!("mission"), symbol_short!("status")).into_val(&env)
+    );
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Started
+    );
+}
+
+#[test]
+fn test_list_submissions_paginated() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let cid = String::from_str(&env, "QmSub");
+    let mut hunters = Vec::new(&env);
+    for _ in 0..3 {
+        let hunter = Address::generate(&env);
+        mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+        client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+        hunters.push_back(hunter);
+    }
+
+    assert_eq!(client.get_submission_count(&mission_id), 3);
+
+    let first_page = client.list_submissions(&mission_id, &0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get(0).unwrap().hunter, hunters.get(0).unwrap());
+    assert_eq!(first_page.get(1).unwrap().hunter, hunters.get(1).unwrap());
+
+    let second_page = client.list_submissions(&mission_id, &2, &2);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get(0).unwrap().hunter, hunters.get(2).unwrap());
+
+    assert_eq!(client.list_submissions(&mission_id, &3, &2).len(), 0);
+}
+
+#[test]
+fn test_list_submissions_reflects_status() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &Vec::new(&env),
+    );
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+
+    let page = client.list_submissions(&mission_id, &0, &10);
+    assert_eq!(page.get(0).unwrap().status, SubmissionStatus::Paid);
+}
+
+#[test]
+fn test_list_missions_with_status_filter() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let first = create_basic_mission(&env, &client, &owner, &token_address, 1);
+    let second = create_basic_mission(&env, &client, &owner, &token_address, 1);
+    let third = create_basic_mission(&env, &client, &owner, &token_address, 1);
+    client.cancel_mission(&second, &owner);
+
+    let all = client.list_missions(&0, &10, &None);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all.get(0).unwrap().id, first);
+    assert_eq!(all.get(2).unwrap().id, third);
+
+    let open = client.list_missions(&0, &10, &Some(MissionStatus::Open));
+    assert_eq!(open.len(), 2);
+    assert_eq!(open.get(1).unwrap().id, third);
+
+    let page = client.list_missions(&1, &1, &None);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, second);
+
+    assert!(client.list_missions(&u64::MAX, &10, &None).is_empty());
+}
+
+#[test]
+fn test_list_missions_clamps_limit_to_page_size() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    for _ in 0..=MAX_PAGE_SIZE {
+        create_basic_mission(&env, &client, &owner, &token_address, 1);
+    }
+
+    // A larger limit still scans one page; the next starts at cursor + MAX_PAGE_SIZE
+    let page = client.list_missions(&0, &100, &None);
+    assert_eq!(page.len(), MAX_PAGE_SIZE);
+    let next = client.list_missions(&(MAX_PAGE_SIZE as u64), &100, &None);
+    assert_eq!(next.len(), 1);
+    assert_eq!(next.get(0).unwrap().id, MAX_PAGE_SIZE as u64 + 1);
+
+    let owned = client.list_missions_by_owner(&owner, &0, &100, &None);
+    assert_eq!(owned.len(), MAX_PAGE_SIZE);
+}
+
+#[test]
+fn test_list_missions_by_owner() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let other_owner = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &other_owner, 10_000);
+
+    let mine = create_basic_mission(&env, &client, &owner, &token_address, 1);
+    let theirs = create_basic_mission(&env, &client, &other_owner, &token_address, 1);
+    let mine_too = create_basic_mission(&env, &client, &owner, &token_address, 1);
+
+    assert_eq!(client.get_owner_mission_count(&owner), 2);
+    assert_eq!(client.get_owner_mission_count(&other_owner), 1);
+
+    let owned = client.list_missions_by_owner(&owner, &0, &10, &None);
+    assert_eq!(owned.len(), 2);
+    assert_eq!(owned.get(0).unwrap().id, mine);
+    assert_eq!(owned.get(1).unwrap().id, mine_too);
+
+    let others = client.list_missions_by_owner(&other_owner, &0, &10, &None);
+    assert_eq!(others.get(0).unwrap().id, theirs);
+
+    client.pause_mission(&mine_too, &owner);
+    let paused = client.list_missions_by_owner(&owner, &0, &10, &Some(MissionStatus::Paused));
+    assert_eq!(paused.len(), 1);
+    assert_eq!(paused.get(0).unwrap().id, mine_too);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_set_fee_config_requires_treasury() {
+    let (env, contract_id, _owner, _token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    client.set_fee_config(&FeeConfig {
+        fee_bps: 100,
+        max_fee: 0,
+        mode: FeeMode::OnFunding,
+        withdrawal_fee_bps: 0,
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+fn test_set_fee_config_above_ceiling() {
+    let (env, contract_id, _owner, _token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    client.set_treasury(&Address::generate(&env));
+
+    client.set_fee_config(&FeeConfig {
+        fee_bps: 1_001,
+        max_fee: 0,
+        mode: FeeMode::OnFunding,
+        withdrawal_fee_bps: 0,
+    });
+}
+
+#[test]
+fn test_fee_on_funding() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    client.set_fee_config(&FeeConfig {
+        fee_bps: 250,
+        max_fee: 0,
+        mode: FeeMode::OnFunding,
+        withdrawal_fee_bps: 0,
+    });
+
+    let owner_balance_before = token_client.balance(&owner);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 4);
+
+    // 2.5% of the 400 escrow goes to the treasury on top of the escrow
+    assert_eq!(token_client.balance(&treasury), 10);
+    assert_eq!(token_client.balance(&contract_id), 400);
+    assert_eq!(token_client.balance(&owner), owner_balance_before - 410);
+
+    // Payouts are not charged again
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &Vec::new(&env),
+    );
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&hunter), 1100);
+    assert_eq!(token_client.balance(&treasury), 10);
+}
+
+#[test]
+fn test_fee_on_payout() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    client.set_fee_config(&FeeConfig {
+        fee_bps: 500,
+        max_fee: 0,
+        mode: FeeMode::OnPayout,
+        withdrawal_fee_bps: 0,
+    });
+
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 4);
+    assert_eq!(token_client.balance(&treasury), 0);
+    assert_eq!(client.get_mission(&mission_id).payout_fee_bps, 500);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &Vec::new(&env),
+    );
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+
+    // Hunter receives the reward minus 5% plus their stake back
+    assert_eq!(token_client.balance(&hunter), 1095);
+    assert_eq!(token_client.balance(&treasury), 5);
+}
+
+#[test]
+fn test_fee_respects_cap() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    client.set_fee_config(&FeeConfig {
+        fee_bps: 1_000,
+        max_fee: 15,
+        mode: FeeMode::OnFunding,
+        withdrawal_fee_bps: 0,
+    });
+
+    create_basic_mission(&env, &client, &owner, &token_address, 4);
+
+    assert_eq!(token_client.balance(&treasury), 15);
+}
+
+#[test]
+fn test_fee_exempt_founder() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    client.set_fee_config(&FeeConfig {
+        fee_bps: 500,
+        max_fee: 0,
+        mode: FeeMode::OnPayout,
+        withdrawal_fee_bps: 0,
+    });
+    client.set_fee_exempt(&owner, &true);
+    assert!(client.is_fee_exempt(&owner));
+
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 4);
+    assert_eq!(client.get_mission(&mission_id).payout_fee_bps, 0);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSub"),
+        &Vec::new(&env),
+    );
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+
+    assert_eq!(token_client.balance(&hunter), 1100);
+    assert_eq!(token_client.balance(&treasury), 0);
+
+    client.set_fee_exempt(&owner, &false);
+    assert!(!client.is_fee_exempt(&owner));
+}
+
+#[test]
+fn test_fee_collected_event() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    client.set_treasury(&Address::generate(&env));
+    client.set_fee_config(&FeeConfig {
+        fee_bps: 100,
+        max_fee: 0,
+        mode: FeeMode::OnFunding,
+        withdrawal_fee_bps: 0,
+    });
+
+    create_basic_mission(&env, &client, &owner, &token_address, 4);
+
+    let fee_topics: Vec<Val> = (symbol_short!("fee"), symbol_short!("collected")).into_val(&env);
+    let fee_events = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(id, topics, _)| id == &contract_id && topics == &fee_topics)
+        .count();
+    assert_eq!(fee_events, 1);
+}
+
+#[test]
+fn test_fund_mission_adds_slots() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 1);
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &first, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &second, 1000);
+
+    let cid = String::from_str(&env, "QmSub");
+    client.submit_feedback(&mission_id, &first, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &second, &cid, &Vec::new(&env));
+
+    client.fund_mission(&mission_id, &2);
+
+    let mission = client.get_mission(&mission_id);
+    assert_eq!(mission.max_participants, 3);
+    assert_eq!(token_client.balance(&contract_id), 300 + 20);
+
+    // Both hunters fit now, and the mission stays open for the third slot
+    client.payout_participant(&mission_id, &owner, &first, &None);
+    client.claim_reward(&mission_id, &first);
+    client.payout_participant(&mission_id, &owner, &second, &None);
+    client.claim_reward(&mission_id, &second);
+    let mission = client.get_mission(&mission_id);
+    assert_eq!(mission.participants_count, 2);
+    assert_eq!(mission.status, MissionStatus::Open);
+
+    client.cancel_mission(&mission_id, &owner);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_fund_mission_zero_slots() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 1);
+
+    client.fund_mission(&mission_id, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_fund_cancelled_mission() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 1);
+
+    client.cancel_mission(&mission_id, &owner);
+    client.fund_mission(&mission_id, &1);
+}
+
+#[test]
+fn test_raise_reward_escrows_remaining_slots() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    let early = Address::generate(&env);
+    let late = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &early, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &late, 1000);
+
+    let cid = String::from_str(&env, "QmSub");
+    client.submit_feedback(&mission_id, &early, &cid, &Vec::new(&env));
+    client.payout_participant(&mission_id, &owner, &early, &None);
+    client.claim_reward(&mission_id, &early);
+
+    let owner_balance_before = token_client.balance(&owner);
+    client.raise_reward(&mission_id, &150);
+
+    // Only the two unpaid slots are topped up
+    assert_eq!(token_client.balance(&owner), owner_balance_before - 100);
+    assert_eq!(client.get_mission(&mission_id).reward_amount, 150);
+
+    client.submit_feedback(&mission_id, &late, &cid, &Vec::new(&env));
+    client.payout_participant(&mission_id, &owner, &late, &None);
+    client.claim_reward(&mission_id, &late);
+    assert_eq!(token_client.balance(&late), 1150);
+
+    client.cancel_mission(&mission_id, &owner);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_raise_reward_must_increase() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    client.raise_reward(&mission_id, &100);
+}
+
+#[test]
+fn test_fund_mission_charges_funding_fee() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    client.set_fee_config(&FeeConfig {
+        fee_bps: 1_000,
+        max_fee: 0,
+        mode: FeeMode::OnFunding,
+        withdrawal_fee_bps: 0,
+    });
+
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 1);
+    assert_eq!(token_client.balance(&treasury), 10);
+
+    client.fund_mission(&mission_id, &2);
+    assert_eq!(token_client.balance(&treasury), 30);
+    assert_eq!(token_client.balance(&contract_id), 300);
+}
+
+#[test]
+fn test_payout_batch_reports_partial_failures() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let cid = String::from_str(&env, "QmSub");
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &first, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &second, 1000);
+    client.submit_feedback(&mission_id, &first, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &second, &cid, &Vec::new(&env));
+
+    let hunters = Vec::from_array(&env, [first.clone(), stranger.clone(), second.clone()]);
+    let results = client.payout_batch(&mission_id, &owner, &hunters, &None);
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results.get(0).unwrap().error, None);
+    assert_eq!(
+        results.get(1).unwrap().error,
+        Some(QuidError::SubmissionNotFound as u32)
+    );
+    assert_eq!(results.get(2).unwrap().error, None);
+
+    client.claim_reward(&mission_id, &first);
+    client.claim_reward(&mission_id, &second);
+    assert_eq!(token_client.balance(&first), 1100);
+    assert_eq!(token_client.balance(&second), 1100);
+    assert_eq!(client.get_mission(&mission_id).participants_count, 2);
+}
+
+#[test]
+fn test_payout_batch_stops_paying_when_full() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 1);
+
+    let cid = String::from_str(&env, "QmSub");
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &first, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &second, 1000);
+    client.submit_feedback(&mission_id, &first, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &second, &cid, &Vec::new(&env));
+
+    let hunters = Vec::from_array(&env, [first.clone(), second.clone(), first.clone()]);
+    let results = client.payout_batch(&mission_id, &owner, &hunters, &None);
+
+    assert_eq!(results.get(0).unwrap().error, None);
+    assert_eq!(
+        results.get(1).unwrap().error,
+        Some(QuidError::MissionClosed as u32)
+    );
+    assert_eq!(
+        results.get(2).unwrap().error,
+        Some(QuidError::MissionClosed as u32)
+    );
+
+    let mission = client.get_mission(&mission_id);
+    assert_eq!(mission.participants_count, 1);
+    assert_eq!(mission.status, MissionStatus::Completed);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+fn test_payout_batch_too_large() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let mut hunters = Vec::new(&env);
+    for _ in 0..=MAX_BATCH_SIZE {
+        hunters.push_back(Address::generate(&env));
+    }
+    client.payout_batch(&mission_id, &owner, &hunters, &None);
+}
+
+#[test]
+fn test_payout_batch_unaffected_by_frozen_hunter() {
+    let (env, contract_id, owner, _) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    // An asset whose issuer can freeze trustlines
+    let token_contract = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    token_contract.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token_address = token_contract.address();
+    mint_tokens_for_hunter(&env, &token_address, &owner, 1_000_000);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 20);
+
+    let cid = String::from_str(&env, "QmSub");
+    let mut hunters = Vec::new(&env);
+    for _ in 0..MAX_BATCH_SIZE {
+        let hunter = Address::generate(&env);
+        mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+        client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+        hunters.push_back(hunter);
+    }
+
+    // The issuer freezes one hunter's trustline before the batch runs
+    let frozen = hunters.get(3).unwrap();
+    StellarAssetClient::new(&env, &token_address).set_authorized(&frozen, &false);
+
+    let results = client.payout_batch(&mission_id, &owner, &hunters, &None);
+    assert_fits_in_transaction(&env);
+    for result in results.iter() {
+        assert_eq!(result.error, None);
+    }
+    assert_eq!(
+        client.get_mission(&mission_id).participants_count,
+        MAX_BATCH_SIZE
+    );
+
+    // Only the frozen hunter's own claim fails
+    assert!(client.try_claim_reward(&mission_id, &frozen).is_err());
+    for hunter in hunters.iter().filter(|hunter| *hunter != frozen) {
+        client.claim_reward(&mission_id, &hunter);
+        assert_eq!(token_client.balance(&hunter), 1100);
+    }
+    assert!(client.get_claimable(&mission_id, &frozen).is_some());
+}
+
+#[test]
+fn test_slash_batch() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+
+    let cid = String::from_str(&env, "QmSpam");
+    let spammer = Address::generate(&env);
+    let other_spammer = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &spammer, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &other_spammer, 1000);
+    client.submit_feedback(&mission_id, &spammer, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &other_spammer, &cid, &Vec::new(&env));
+
+    let hunters = Vec::from_array(&env, [spammer.clone(), stranger, other_spammer.clone()]);
+    let results = client.slash_batch(
+        &mission_id,
+        &owner,
+        &hunters,
+        &String::from_str(&env, "QmReason"),
+    );
+
+    assert_eq!(results.get(0).unwrap().error, None);
+    assert_eq!(
+        results.get(1).unwrap().error,
+        Some(QuidError::StakeNotFound as u32)
+    );
+    assert_eq!(results.get(2).unwrap().error, None);
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    client.finalize_slash(&mission_id, &spammer);
+    client.finalize_slash(&mission_id, &other_spammer);
+    assert_eq!(token_client.balance(&treasury), 20);
+}
+
+/// A 500 and a 200 prize ahead of the base reward.
+fn prize_tiers(env: &Env) -> Vec<RewardTier> {
+    Vec::from_array(
+        env,
+        [
+            RewardTier {
+                amount: 500,
+                count: 1,
+            },
+            RewardTier {
+                amount: 200,
+                count: 1,
+            },
+        ],
+    )
+}
+
+#[test]
+fn test_tiered_mission_escrow_and_payouts() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(20)
+        .tiers(prize_tiers(&env))
+        .no_stake()
+        .create(&client, &owner);
+    // 500 + 200 + 3 * 20
+    assert_eq!(token_client.balance(&contract_id), 760);
+
+    let cid = String::from_str(&env, "QmFeedback");
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.submit_feedback(&mission_id, &first, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &second, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &other, &cid, &Vec::new(&env));
+
+    client.payout_participant(&mission_id, &owner, &first, &Some(0));
+    client.payout_participant(&mission_id, &owner, &second, &Some(1));
+    client.payout_participant(&mission_id, &owner, &other, &None);
+    client.claim_reward(&mission_id, &first);
+    client.claim_reward(&mission_id, &second);
+    client.claim_reward(&mission_id, &other);
+
+    assert_eq!(token_client.balance(&first), 500);
+    assert_eq!(token_client.balance(&second), 200);
+    assert_eq!(token_client.balance(&other), 20);
+
+    let mission = client.get_mission(&mission_id);
+    assert_eq!(mission.participants_count, 3);
+    assert_eq!(mission.tier_paid, Vec::from_array(&env, [1u32, 1u32]));
+    assert_eq!(token_client.balance(&contract_id), 40);
+}
+
+#[test]
+fn test_tiered_payout_tier_full() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(20)
+        .tiers(prize_tiers(&env))
+        .no_stake()
+        .create(&client, &owner);
+
+    let cid = String::from_str(&env, "QmFeedback");
+    let first = Address::generate(&env);
+    let also_first = Address::generate(&env);
+    client.submit_feedback(&mission_id, &first, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &also_first, &cid, &Vec::new(&env));
+
+    client.payout_participant(&mission_id, &owner, &first, &Some(0));
+    let result = client.try_payout_participant(&mission_id, &owner, &also_first, &Some(0));
+    assert_eq!(result, Err(Ok(QuidError::TierFull)));
+
+    let result = client.try_payout_participant(&mission_id, &owner, &also_first, &Some(2));
+    assert_eq!(result, Err(Ok(QuidError::InvalidTier)));
+
+    // The submission is still pending and can be paid from another tier
+    client.payout_participant(&mission_id, &owner, &also_first, &Some(1));
+}
+
+#[test]
+fn test_tiered_base_slots_exhausted() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    // Both slots are prize tiers, leaving none at the base reward
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(20)
+        .tiers(prize_tiers(&env))
+        .slots(2)
+        .no_stake()
+        .create(&client, &owner);
+
+    let hunter = Address::generate(&env);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+
+    let result = client.try_payout_participant(&mission_id, &owner, &hunter, &None);
+    assert_eq!(result, Err(Ok(QuidError::TierFull)));
+}
+
+#[test]
+fn test_tiered_cancel_refunds_unused_tiers() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let owner_balance_before = token_client.balance(&owner);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(20)
+        .tiers(prize_tiers(&env))
+        .no_stake()
+        .create(&client, &owner);
+
+    let hunter = Address::generate(&env);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+    client.payout_participant(&mission_id, &owner, &hunter, &Some(1));
+    client.claim_reward(&mission_id, &hunter);
+
+    client.cancel_mission(&mission_id, &owner);
+
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(token_client.balance(&owner), owner_balance_before - 200);
+}
+
+#[test]
+fn test_tiered_raise_reward_only_affects_base_slots() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(20)
+        .tiers(prize_tiers(&env))
+        .no_stake()
+        .create(&client, &owner);
+
+    client.raise_reward(&mission_id, &30);
+    // Three base slots at +10 each
+    assert_eq!(token_client.balance(&contract_id), 790);
+
+    let hunter = Address::generate(&env);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+    client.payout_participant(&mission_id, &owner, &hunter, &Some(0));
+    client.claim_reward(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&hunter), 500);
+}
+
+#[test]
+fn test_tiers_exceeding_max_participants_rejected() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let result = MissionBuilder::new(&env, &token_address)
+        .reward(20)
+        .tiers(Vec::from_array(
+            &env,
+            [RewardTier {
+                amount: 500,
+                count: 3,
+            }],
+        ))
+        .slots(2)
+        .no_stake()
+        .try_create(&client, &owner);
+    assert_eq!(result, Err(Ok(QuidError::InvalidAmount)));
+}
+
+#[test]
+fn test_approve_then_claim_reward() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+
+    client.approve_submission(&mission_id, &owner, &hunter, &None);
+
+    // Nothing moves until the hunter claims; the stake stays escrowed
+    assert_eq!(token_client.balance(&hunter), 990);
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Approved
+    );
+    assert_eq!(client.get_mission(&mission_id).participants_count, 1);
+    assert_eq!(
+        client.get_claimable(&mission_id, &hunter),
+        Some(ClaimableBalance {
+            mission_id,
+            token: token_address.clone(),
+            amount: 100,
+            stake_token: Some(token_address.clone()),
+            stake_amount: 10,
+        })
+    );
+
+    assert_eq!(client.claim_reward(&mission_id, &hunter), 100);
+    assert_eq!(token_client.balance(&hunter), 1100);
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Paid
+    );
+    assert_eq!(client.get_claimable(&mission_id, &hunter), None);
+    assert_eq!(client.list_claimable(&hunter).len(), 0);
+
+    let result = client.try_claim_reward(&mission_id, &hunter);
+    assert_eq!(result, Err(Ok(QuidError::NothingToClaim)));
+}
+
+#[test]
+fn test_approve_twice_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+
+    client.approve_submission(&mission_id, &owner, &hunter, &None);
+    let result = client.try_approve_submission(&mission_id, &owner, &hunter, &None);
+    assert_eq!(result, Err(Ok(QuidError::NotPending)));
+    let result = client.try_payout_participant(&mission_id, &owner, &hunter, &None);
+    assert_eq!(result, Err(Ok(QuidError::NotPending)));
+}
+
+#[test]
+fn test_claim_all_across_missions() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let first_mission = create_basic_mission(&env, &client, &owner, &token_address, 5);
+    let second_mission = MissionBuilder::new(&env, &token_address)
+        .reward(20)
+        .tiers(prize_tiers(&env))
+        .no_stake()
+        .create(&client, &owner);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    let cid = String::from_str(&env, "QmFeedback");
+    client.submit_feedback(&first_mission, &hunter, &cid, &Vec::new(&env));
+    client.submit_feedback(&second_mission, &hunter, &cid, &Vec::new(&env));
+
+    client.approve_submission(&first_mission, &owner, &hunter, &None);
+    client.approve_submission(&second_mission, &owner, &hunter, &Some(0));
+
+    let claimable = client.list_claimable(&hunter);
+    assert_eq!(claimable.len(), 2);
+    assert_eq!(claimable.get(0).unwrap().mission_id, first_mission);
+    assert_eq!(claimable.get(1).unwrap().amount, 500);
+
+    let claimed = client.claim_all(&hunter);
+    assert_eq!(claimed, claimable);
+    assert_eq!(token_client.balance(&hunter), 1600);
+    assert_eq!(client.list_claimable(&hunter).len(), 0);
+
+    let result = client.try_claim_all(&hunter);
+    assert_eq!(result, Err(Ok(QuidError::NothingToClaim)));
+}
+
+#[test]
+fn test_cancel_keeps_approved_rewards_claimable() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let owner_balance_before = token_client.balance(&owner);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+    client.approve_submission(&mission_id, &owner, &hunter, &None);
+
+    client.cancel_mission(&mission_id, &owner);
+    assert_eq!(token_client.balance(&owner), owner_balance_before - 100);
+    // The approved reward and the winner's stake stay escrowed for the claim
+    assert_eq!(token_client.balance(&contract_id), 110);
+
+    client.claim_reward(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(token_client.balance(&hunter), 1100);
+}
+
+#[test]
+fn test_approve_deducts_payout_fee() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    client.set_fee_config(&FeeConfig {
+        fee_bps: 500,
+        max_fee: 0,
+        mode: FeeMode::OnPayout,
+        withdrawal_fee_bps: 0,
+    });
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+    client.approve_submission(&mission_id, &owner, &hunter, &None);
+
+    assert_eq!(token_client.balance(&treasury), 5);
+    assert_eq!(client.claim_reward(&mission_id, &hunter), 95);
+}
+
+/// Basic mission with one staked submission that has been slashed.
+fn setup_slashed_hunter(
+    env: &Env,
+    client: &QuidStoreContractClient,
+    owner: &Address,
+    token_address: &Address,
+) -> (u64, Address, Address) {
+    let treasury = Address::generate(env);
+    client.set_treasury(&treasury);
+    let mission_id = create_basic_mission(env, client, owner, token_address, 5);
+
+    let hunter = Address::generate(env);
+    mint_tokens_for_hunter(env, token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(env, "QmSpam"),
+        &Vec::new(env),
+    );
+    client.slash_hunter_stake(
+        &mission_id,
+        owner,
+        &hunter,
+        &String::from_str(env, "QmReason"),
+    );
+
+    (mission_id, hunter, treasury)
+}
+
+#[test]
+fn test_slash_held_pending_dispute() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let (mission_id, hunter, treasury) =
+        setup_slashed_hunter(&env, &client, &owner, &token_address);
+
+    let record = client.get_slash(&mission_id, &hunter);
+    assert_eq!(record.amount, 10);
+    assert_eq!(record.reason_cid, String::from_str(&env, "QmReason"));
+    assert_eq!(record.status, SlashStatus::Pending);
+    assert_eq!(token_client.balance(&treasury), 0);
+
+    let result = client.try_finalize_slash(&mission_id, &hunter);
+    assert_eq!(result, Err(Ok(QuidError::DisputeWindowOpen)));
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    client.finalize_slash(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&treasury), 10);
+    assert_eq!(
+        client.get_slash(&mission_id, &hunter).status,
+        SlashStatus::Upheld
+    );
+
+    let result = client.try_finalize_slash(&mission_id, &hunter);
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+}
+
+#[test]
+fn test_appeal_overturned_refunds_hunter() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let (mission_id, hunter, treasury) =
+        setup_slashed_hunter(&env, &client, &owner, &token_address);
+
+    let arbiter = Address::generate(&env);
+    client.set_arbiter(&arbiter);
+    assert_eq!(client.get_arbiter(), Some(arbiter));
+
+    let evidence_cid = String::from_str(&env, "QmEvidence");
+    client.appeal_slash(&mission_id, &hunter, &evidence_cid);
+
+    let record = client.get_slash(&mission_id, &hunter);
+    assert_eq!(record.status, SlashStatus::Appealed);
+    assert_eq!(record.evidence_cid, Some(evidence_cid));
+
+    // An appealed slash can no longer be finalized by the clock
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    let result = client.try_finalize_slash(&mission_id, &hunter);
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+
+    client.resolve_appeal(&mission_id, &hunter, &false);
+    assert_eq!(token_client.balance(&hunter), 1000);
+    assert_eq!(token_client.balance(&treasury), 0);
+    assert_eq!(
+        client.get_slash(&mission_id, &hunter).status,
+        SlashStatus::Overturned
+    );
+}
+
+#[test]
+fn test_appeal_upheld_pays_treasury() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let (mission_id, hunter, treasury) =
+        setup_slashed_hunter(&env, &client, &owner, &token_address);
+
+    client.appeal_slash(&mission_id, &hunter, &String::from_str(&env, "QmEvidence"));
+
+    // No arbiter appointed, so the treasury resolves
+    client.resolve_appeal(&mission_id, &hunter, &true);
+    assert_eq!(token_client.balance(&treasury), 10);
+
+    let result = client.try_resolve_appeal(&mission_id, &hunter, &false);
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+}
+
+#[test]
+fn test_appeal_after_window_fails() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let (mission_id, hunter, _) = setup_slashed_hunter(&env, &client, &owner, &token_address);
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    let result = client.try_appeal_slash(&mission_id, &hunter, &String::from_str(&env, "QmLate"));
+    assert_eq!(result, Err(Ok(QuidError::DisputeWindowClosed)));
+}
+
+#[test]
+fn test_resolve_requires_appeal() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let (mission_id, hunter, _) = setup_slashed_hunter(&env, &client, &owner, &token_address);
+
+    let result = client.try_resolve_appeal(&mission_id, &hunter, &false);
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+
+    let stranger = Address::generate(&env);
+    let result = client.try_get_slash(&mission_id, &stranger);
+    assert_eq!(result, Err(Ok(QuidError::SlashNotFound)));
+}
+
+#[test]
+fn test_slash_events() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let (mission_id, hunter, _) = setup_slashed_hunter(&env, &client, &owner, &token_address);
+
+    client.appeal_slash(&mission_id, &hunter, &String::from_str(&env, "QmEvidence"));
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    assert_eq!(event.0, contract_id);
+    assert_eq!(
+        event.1,
+        (symbol_short!("slash"), symbol_short!("appeal")).into_val(&env)
+    );
+
+    client.resolve_appeal(&mission_id, &hunter, &true);
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    assert_eq!(event.0, contract_id);
+    assert_eq!(
+        event.1,
+        (symbol_short!("slash"), symbol_short!("resolve")).into_val(&env)
+    );
+}
+
+#[test]
+fn test_reviewer_can_review_but_not_slash_or_pause() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let reviewer = Address::generate(&env);
+    client.add_reviewer(&mission_id, &owner, &reviewer, &MissionRole::Reviewer);
+    assert_eq!(
+        client.get_role(&mission_id, &reviewer),
+        Some(MissionRole::Reviewer)
+    );
+
+    let cid = String::from_str(&env, "QmFeedback");
+    let winner = Address::generate(&env);
+    let loser = Address::generate(&env);
+    let spammer = Address::generate(&env);
+    for hunter in [&winner, &loser, &spammer] {
+        mint_tokens_for_hunter(&env, &token_address, hunter, 1000);
+        client.submit_feedback(&mission_id, hunter, &cid, &Vec::new(&env));
+    }
+
+    client.payout_participant(&mission_id, &reviewer, &winner, &None);
+    client.claim_reward(&mission_id, &winner);
+    client.reject_submission(&mission_id, &reviewer, &loser, &cid);
+
+    let reason_cid = String::from_str(&env, "QmReason");
+    let result = client.try_slash_hunter_stake(&mission_id, &reviewer, &spammer, &reason_cid);
+    assert_eq!(result, Err(Ok(QuidError::NotAuthorized)));
+    let result = client.try_pause_mission(&mission_id, &reviewer);
+    assert_eq!(result, Err(Ok(QuidError::NotAuthorized)));
+}
+
+#[test]
+fn test_manager_can_slash_and_pause_but_not_cancel() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let manager = Address::generate(&env);
+    client.add_reviewer(&mission_id, &owner, &manager, &MissionRole::Manager);
+
+    let spammer = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &spammer, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &spammer,
+        &String::from_str(&env, "QmSpam"),
+        &Vec::new(&env),
+    );
+
+    client.slash_hunter_stake(
+        &mission_id,
+        &manager,
+        &spammer,
+        &String::from_str(&env, "QmReason"),
+    );
+    client.pause_mission(&mission_id, &manager);
+    client.resume_mission(&mission_id, &manager);
+
+    let result = client.try_cancel_mission(&mission_id, &manager);
+    assert_eq!(result, Err(Ok(QuidError::NotAuthorized)));
+    let result = client.try_update_mission_status(&mission_id, &manager, &MissionStatus::Cancelled);
+    assert_eq!(result, Err(Ok(QuidError::NotAuthorized)));
+    let result = client.try_add_reviewer(
+        &mission_id,
+        &manager,
+        &Address::generate(&env),
+        &MissionRole::Reviewer,
+    );
+    assert_eq!(result, Err(Ok(QuidError::NotAuthorized)));
+}
+
+#[test]
+fn test_co_owner_can_cancel_and_manage_reviewers() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let co_owner = Address::generate(&env);
+    let reviewer = Address::generate(&env);
+    client.add_reviewer(&mission_id, &owner, &co_owner, &MissionRole::Owner);
+    client.add_reviewer(&mission_id, &co_owner, &reviewer, &MissionRole::Reviewer);
+
+    client.cancel_mission(&mission_id, &co_owner);
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_remove_reviewer_revokes_access() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let reviewer = Address::generate(&env);
+    client.add_reviewer(&mission_id, &owner, &reviewer, &MissionRole::Reviewer);
+    client.remove_reviewer(&mission_id, &owner, &reviewer);
+    assert_eq!(client.get_role(&mission_id, &reviewer), None);
+    assert_eq!(
+        client.get_role(&mission_id, &owner),
+        Some(MissionRole::Owner)
+    );
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+    let result = client.try_payout_participant(&mission_id, &reviewer, &hunter, &None);
+    assert_eq!(result, Err(Ok(QuidError::NotAuthorized)));
+
+    let result = client.try_remove_reviewer(&mission_id, &owner, &reviewer);
+    assert_eq!(result, Err(Ok(QuidError::ReviewerNotFound)));
+}
+
+#[test]
+fn test_reviewers_capped_per_mission() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let first = Address::generate(&env);
+    client.add_reviewer(&mission_id, &owner, &first, &MissionRole::Reviewer);
+    for _ in 1..MAX_REVIEWERS {
+        let reviewer = Address::generate(&env);
+        client.add_reviewer(&mission_id, &owner, &reviewer, &MissionRole::Reviewer);
+    }
+
+    // Changing an existing role does not take another slot
+    client.add_reviewer(&mission_id, &owner, &first, &MissionRole::Manager);
+    let extra = Address::generate(&env);
+    let result = client.try_add_reviewer(&mission_id, &owner, &extra, &MissionRole::Reviewer);
+    assert_eq!(result, Err(Ok(QuidError::BatchTooLarge)));
+
+    client.remove_reviewer(&mission_id, &owner, &first);
+    client.add_reviewer(&mission_id, &owner, &extra, &MissionRole::Reviewer);
+}
+
+/// Register a reputation contract, point the store at it and authorize the
+/// store as a reporter.
+fn setup_reputation(
+    env: &Env,
+    client: &QuidStoreContractClient,
+) -> quid_reputation::QuidReputationContractClient<'static> {
+    let treasury = Address::generate(env);
+    client.set_treasury(&treasury);
+
+    let reputation_id = env.register(quid_reputation::QuidReputationContract, ());
+    let reputation = quid_reputation::QuidReputationContractClient::new(env, &reputation_id);
+    let admin = Address::generate(env);
+    reputation.initialize(&admin);
+    reputation.add_reporter(&admin, &client.address);
+    client.set_reputation_contract(&reputation_id);
+    reputation
+}
+
+/// One whole unit of `token` in base units, the smallest reward that earns
+/// reputation.
+fn whole_unit(env: &Env, token: &Address) -> i128 {
+    10i128.pow(TokenClient::new(env, token).decimals())
+}
+
+#[test]
+fn test_reputation_gate_score_or_attestation() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let reputation = setup_reputation(&env, &client);
+
+    let tester = String::from_str(&env, "verified-tester");
+    let issuer = Address::generate(&env);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reputation(ReputationGate {
+            min_score: Some(50),
+            min_missions_completed: None,
+            attestation_type: Some(tester.clone()),
+            attestation_issuer: Some(issuer.clone()),
+        })
+        .no_stake()
+        .create(&client, &owner);
+
+    let cid = String::from_str(&env, "QmFeedback");
+    let newcomer = Address::generate(&env);
+    let result = client.try_submit_feedback(&mission_id, &newcomer, &cid, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(QuidError::InsufficientReputation)));
+
+    let veteran = Address::generate(&env);
+    reputation.set_profile(&quid_reputation::Profile {
+        subject: veteran.clone(),
+        score: 50,
+        missions_completed: 0,
+        missions_created: 0,
+    });
+    client.submit_feedback(&mission_id, &veteran, &cid, &Vec::new(&env));
+
+    // Attestations from anyone but the trusted issuer do not count
+    let verified = Address::generate(&env);
+    reputation.issue_attestation(
+        &verified,
+        &verified,
+        &tester,
+        &String::from_str(&env, "QmProof"),
+    );
+    let result = client.try_submit_feedback(&mission_id, &verified, &cid, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(QuidError::InsufficientReputation)));
+
+    reputation.issue_attestation(
+        &issuer,
+        &verified,
+        &tester,
+        &String::from_str(&env, "QmProof"),
+    );
+    client.submit_feedback(&mission_id, &verified, &cid, &Vec::new(&env));
+}
+
+#[test]
+fn test_reputation_gate_low_score_and_revoked_attestation() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let reputation = setup_reputation(&env, &client);
+
+    let tester = String::from_str(&env, "verified-tester");
+    let issuer = Address::generate(&env);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reputation(ReputationGate {
+            min_score: None,
+            min_missions_completed: Some(3),
+            attestation_type: Some(tester.clone()),
+            attestation_issuer: Some(issuer.clone()),
+        })
+        .no_stake()
+        .create(&client, &owner);
+
+    let hunter = Address::generate(&env);
+    reputation.set_profile(&quid_reputation::Profile {
+        subject: hunter.clone(),
+        score: 90,
+        missions_completed: 2,
+        missions_created: 0,
+    });
+    let attestation_id = reputation.issue_attestation(
+        &issuer,
+        &hunter,
+        &tester,
+        &String::from_str(&env, "QmProof"),
+    );
+    reputation.revoke_attestation(&issuer, &attestation_id);
+
+    let result = client.try_submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+    assert_eq!(result, Err(Ok(QuidError::InsufficientReputation)));
+}
+
+#[test]
+fn test_reputation_gate_requires_contract() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let result = MissionBuilder::new(&env, &token_address)
+        .reputation(ReputationGate {
+            min_score: Some(50),
+            min_missions_completed: None,
+            attestation_type: None,
+            attestation_issuer: None,
+        })
+        .no_stake()
+        .try_create(&client, &owner);
+    assert_eq!(result, Err(Ok(QuidError::ReputationNotSet)));
+}
+
+#[test]
+fn test_reputation_gate_attestation_requires_issuer() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    setup_reputation(&env, &client);
+
+    let result = MissionBuilder::new(&env, &token_address)
+        .reputation(ReputationGate {
+            min_score: None,
+            min_missions_completed: None,
+            attestation_type: Some(String::from_str(&env, "verified-tester")),
+            attestation_issuer: None,
+        })
+        .no_stake()
+        .try_create(&client, &owner);
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+}
+
+#[test]
+fn test_marketplace_activity_updates_reputation() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let reputation = setup_reputation(&env, &client);
+    client.set_token_vetted(&token_address, &true);
+
+    // The owner is credited when the mission pays out, not when it is created
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(whole_unit(&env, &token_address))
+        .create(&client, &owner);
+    assert!(!reputation.profile_exists(&owner));
+
+    let cid = String::from_str(&env, "QmFeedback");
+    let winner = Address::generate(&env);
+    let spammer = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &winner, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &spammer, 1000);
+    client.submit_feedback(&mission_id, &winner, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &spammer, &cid, &Vec::new(&env));
+
+    client.payout_participant(&mission_id, &owner, &winner, &None);
+    client.claim_reward(&mission_id, &winner);
+    let profile = reputation.get_profile(&winner);
+    assert_eq!(profile.missions_completed, 1);
+    assert_eq!(profile.score, 10);
+    assert_eq!(reputation.get_profile(&owner).missions_created, 1);
+
+    // The penalty only lands once the slash is final
+    client.slash_hunter_stake(
+        &mission_id,
+        &owner,
+        &spammer,
+        &String::from_str(&env, "QmReason"),
+    );
+    assert!(!reputation.profile_exists(&spammer));
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    client.finalize_slash(&mission_id, &spammer);
+    assert_eq!(reputation.get_profile(&spammer).score, -25);
+}
+
+#[test]
+fn test_reputation_not_farmed_by_dust_or_self_payouts() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let reputation = setup_reputation(&env, &client);
+    client.set_token_vetted(&token_address, &true);
+    let cid = String::from_str(&env, "QmFeedback");
+
+    // A dust reward credits neither the owner nor the sybil it pays
+    let dust = create_basic_mission(&env, &client, &owner, &token_address, 5);
+    let sybil = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &sybil, 1000);
+    client.submit_feedback(&dust, &sybil, &cid, &Vec::new(&env));
+    client.payout_participant(&dust, &owner, &sybil, &None);
+    assert!(!reputation.profile_exists(&sybil));
+    assert!(!reputation.profile_exists(&owner));
+
+    // An owner paying their own submission earns nothing either
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(whole_unit(&env, &token_address))
+        .create(&client, &owner);
+    client.submit_feedback(&mission_id, &owner, &cid, &Vec::new(&env));
+    client.payout_participant(&mission_id, &owner, &owner, &None);
+    assert!(!reputation.profile_exists(&owner));
+}
+
+#[test]
+fn test_owner_credited_once_on_first_qualifying_payout() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let reputation = setup_reputation(&env, &client);
+    client.set_token_vetted(&token_address, &true);
+    let cid = String::from_str(&env, "QmFeedback");
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(whole_unit(&env, &token_address))
+        .slots(3)
+        .create(&client, &owner);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    for hunter in [&first, &second] {
+        mint_tokens_for_hunter(&env, &token_address, hunter, 1000);
+        client.submit_feedback(&mission_id, hunter, &cid, &Vec::new(&env));
+    }
+
+    // A self-payout fills a slot but does not use up the owner's credit
+    client.submit_feedback(&mission_id, &owner, &cid, &Vec::new(&env));
+    client.payout_participant(&mission_id, &owner, &owner, &None);
+    assert!(!reputation.profile_exists(&owner));
+
+    client.payout_participant(&mission_id, &owner, &first, &None);
+    assert_eq!(reputation.get_profile(&owner).missions_created, 1);
+    client.payout_participant(&mission_id, &owner, &second, &None);
+    assert_eq!(reputation.get_profile(&owner).missions_created, 1);
+}
+
+/// Token with 2 decimals, so one whole unit is 100 base units.
+#[soroban_sdk::contract]
+struct CentToken;
+
+#[soroban_sdk::contractimpl]
+impl CentToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().instance().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().instance().get(&id).unwrap_or(0)
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        2
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let from_balance = Self::balance(env.clone(), from.clone());
+        env.storage()
+            .instance()
+            .set(&from, &(from_balance - amount));
+        Self::mint(env, to, amount);
+    }
+}
+
+#[test]
+fn test_reputation_threshold_follows_token_decimals() {
+    let (env, contract_id, owner, _) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let reputation = setup_reputation(&env, &client);
+    let token_address = env.register(CentToken, ());
+    CentTokenClient::new(&env, &token_address).mint(&owner, &1_000_000);
+    client.set_token_vetted(&token_address, &true);
+    let cid = String::from_str(&env, "QmFeedback");
+
+    // A whole unit of a 2-decimal token is 100 base units
+    let dust = MissionBuilder::new(&env, &token_address)
+        .reward(99)
+        .no_stake()
+        .create(&client, &owner);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(100)
+        .no_stake()
+        .create(&client, &owner);
+    let hunter = Address::generate(&env);
+    client.submit_feedback(&dust, &hunter, &cid, &Vec::new(&env));
+    client.payout_participant(&dust, &owner, &hunter, &None);
+    assert!(!reputation.profile_exists(&hunter));
+
+    client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    assert_eq!(reputation.get_profile(&hunter).missions_completed, 1);
+    assert_eq!(reputation.get_profile(&owner).missions_created, 1);
+}
+
+#[test]
+fn test_reputation_not_farmed_by_unvetted_token_or_cancelled_mission() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let reputation = setup_reputation(&env, &client);
+    let cid = String::from_str(&env, "QmFeedback");
+
+    // A freshly minted token pays real-looking rewards but earns nothing
+    let sybil = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &sybil, 1000);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(whole_unit(&env, &token_address))
+        .create(&client, &owner);
+    client.submit_feedback(&mission_id, &sybil, &cid, &Vec::new(&env));
+    client.payout_participant(&mission_id, &owner, &sybil, &None);
+    assert!(!reputation.profile_exists(&sybil));
+    assert!(!reputation.profile_exists(&owner));
+
+    // A vetted mission cancelled before any payout is refunded in full
+    client.set_token_vetted(&token_address, &true);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .reward(whole_unit(&env, &token_address))
+        .create(&client, &owner);
+    client.cancel_mission(&mission_id, &owner);
+    assert!(!reputation.profile_exists(&owner));
+}
+
+#[test]
+fn test_overturned_slash_leaves_reputation() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let reputation = setup_reputation(&env, &client);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+    client.slash_hunter_stake(
+        &mission_id,
+        &owner,
+        &hunter,
+        &String::from_str(&env, "QmReason"),
+    );
+    client.appeal_slash(&mission_id, &hunter, &String::from_str(&env, "QmEvidence"));
+    client.resolve_appeal(&mission_id, &hunter, &false);
+
+    assert!(!reputation.profile_exists(&hunter));
+}
+
+#[test]
+fn test_unregistered_store_still_pays_out() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    let reputation_id = env.register(quid_reputation::QuidReputationContract, ());
+    let reputation = quid_reputation::QuidReputationContractClient::new(&env, &reputation_id);
+    reputation.initialize(&Address::generate(&env));
+    client.set_reputation_contract(&reputation_id);
+
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+
+    assert_eq!(token_client.balance(&hunter), 1100);
+    assert!(!reputation.profile_exists(&hunter));
+    assert!(!reputation.profile_exists(&owner));
+}
+
+fn register_gating_token(env: &Env) -> Address {
+    env.register_stellar_asset_contract_v2(Address::generate(env))
+        .address()
+}
+
+#[test]
+fn test_asset_gate_any_rule() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let gov = register_gating_token(&env);
+    let membership = register_gating_token(&env);
+    let requirements = Vec::from_array(
+        &env,
+        [
+            AssetRequirement {
+                token: gov.clone(),
+                min_amount: 100,
+            },
+            AssetRequirement {
+                token: membership.clone(),
+                min_amount: 1,
+            },
+        ],
+    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .requirements(requirements)
+        .rule(GateRule::Any)
+        .no_stake()
+        .create(&client, &owner);
+
+    let cid = String::from_str(&env, "QmFeedback");
+    let member = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &membership, &member, 1);
+    client.submit_feedback(&mission_id, &member, &cid, &Vec::new(&env));
+
+    let outsider = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &gov, &outsider, 99);
+    let result = client.try_submit_feedback(&mission_id, &outsider, &cid, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(QuidError::InsufficientAssetBalance)));
+}
+
+#[test]
+fn test_asset_gate_all_rule_reports_failed_requirement() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let usdc = register_gating_token(&env);
+    let kyc_badge = register_gating_token(&env);
+    let requirements = Vec::from_array(
+        &env,
+        [
+            AssetRequirement {
+                token: usdc.clone(),
+                min_amount: 10,
+            },
+            AssetRequirement {
+                token: kyc_badge.clone(),
+                min_amount: 1,
+            },
+        ],
+    );
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .requirements(requirements)
+        .no_stake()
+        .create(&client, &owner);
+
+    let cid = String::from_str(&env, "QmFeedback");
+    let hunter = Address::generate(&env);
+    let result = client.try_submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(QuidError::AssetRequirement0Unmet)));
+
+    mint_tokens_for_hunter(&env, &usdc, &hunter, 10);
+    let result = client.try_submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(QuidError::AssetRequirement1Unmet)));
+
+    mint_tokens_for_hunter(&env, &kyc_badge, &hunter, 1);
+    client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+}
+
+#[test]
+fn test_asset_gate_too_many_requirements() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let mut requirements = Vec::new(&env);
+    for _ in 0..5 {
+        requirements.push_back(AssetRequirement {
+            token: register_gating_token(&env),
+            min_amount: 1,
+        });
+    }
+    let result = MissionBuilder::new(&env, &token_address)
+        .requirements(requirements)
+        .rule(GateRule::Any)
+        .no_stake()
+        .try_create(&client, &owner);
+    assert_eq!(result, Err(Ok(QuidError::InvalidAmount)));
+}
+
+fn merkle_leaf(env: &Env, account: &Address) -> BytesN<32> {
+    env.crypto().sha256(&account.clone().to_xdr(env)).into()
+}
+
+fn merkle_parent(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut pair = Bytes::from(left.clone());
+    pair.append(&Bytes::from(right.clone()));
+    env.crypto().sha256(&pair).into()
+}
+
+#[test]
+fn test_address_allowlist() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let invitee = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let allowlist = Allowlist::Addresses(Vec::from_array(&env, [invitee.clone()]));
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .allowlist(allowlist.clone())
+        .no_stake()
+        .create(&client, &owner);
+    assert_eq!(client.get_mission(&mission_id).allowlist, allowlist);
+
+    let cid = String::from_str(&env, "QmFeedback");
+    client.submit_feedback(&mission_id, &invitee, &cid, &Vec::new(&env));
+
+    let result = client.try_submit_feedback(&mission_id, &outsider, &cid, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(QuidError::NotAllowlisted)));
+}
+
+#[test]
+fn test_address_allowlist_rejects_empty_or_oversized_list() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let result = MissionBuilder::new(&env, &token_address)
+        .allowlist(Allowlist::Addresses(Vec::new(&env)))
+        .no_stake()
+        .try_create(&client, &owner);
+    assert_eq!(result, Err(Ok(QuidError::InvalidAllowlist)));
+
+    let mut invitees = Vec::new(&env);
+    for _ in 0..MAX_ALLOWLIST_SIZE {
+        invitees.push_back(Address::generate(&env));
+    }
+    MissionBuilder::new(&env, &token_address)
+        .allowlist(Allowlist::Addresses(invitees.clone()))
+        .no_stake()
+        .create(&client, &owner);
+
+    invitees.push_back(Address::generate(&env));
+    let result = MissionBuilder::new(&env, &token_address)
+        .allowlist(Allowlist::Addresses(invitees))
+        .no_stake()
+        .try_create(&client, &owner);
+    assert_eq!(result, Err(Ok(QuidError::InvalidAllowlist)));
+}
+
+#[test]
+fn test_merkle_allowlist_proofs() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let invitees: [Address; 4] = core::array::from_fn(|_| Address::generate(&env));
+    let leaves = invitees
+        .each_ref()
+        .map(|account| merkle_leaf(&env, account));
+    let left = merkle_parent(&env, &leaves[0], &leaves[1]);
+    let right = merkle_parent(&env, &leaves[2], &leaves[3]);
+    let root = merkle_parent(&env, &left, &right);
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .allowlist(Allowlist::MerkleRoot(root))
+        .no_stake()
+        .create(&client, &owner);
+
+    let cid = String::from_str(&env, "QmFeedback");
+    let proof = Vec::from_array(&env, [leaves[3].clone(), left.clone()]);
+    client.submit_feedback(&mission_id, &invitees[2], &cid, &proof);
+    assert_eq!(
+        client.get_submission(&mission_id, &invitees[2]).status,
+        SubmissionStatus::Pending
+    );
+
+    // Another invitee's proof does not work for a different address.
+    let result = client.try_submit_feedback(&mission_id, &invitees[3], &cid, &proof);
+    assert_eq!(result, Err(Ok(QuidError::NotAllowlisted)));
+
+    let outsider = Address::generate(&env);
+    let result = client.try_submit_feedback(&mission_id, &outsider, &cid, &proof);
+    assert_eq!(result, Err(Ok(QuidError::NotAllowlisted)));
+
+    let result = client.try_submit_feedback(&mission_id, &invitees[0], &cid, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(QuidError::NotAllowlisted)));
+}
+
+/// `sha256(mission_id || hunter || cid || salt)`, as a frontend would build it.
+fn commit_hash(
+    env: &Env,
+    mission_id: u64,
+    hunter: &Address,
+    cid: &str,
+    salt: &Bytes,
+) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &mission_id.to_be_bytes());
+    preimage.append(&hunter.to_string().to_bytes());
+    preimage.append(&String::from_str(env, cid).to_bytes());
+    preimage.append(salt);
+    env.crypto().sha256(&preimage).into()
+}
+
+#[test]
+fn test_commit_reveal_flow() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    client.set_treasury(&Address::generate(&env));
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .commit_reveal()
+        .create(&client, &owner);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    let cid = String::from_str(&env, "QmFeedback");
+    let salt = Bytes::from_array(&env, &[7; 16]);
+
+    let result = client.try_submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+
+    client.commit_submission(
+        &mission_id,
+        &hunter,
+        &commit_hash(&env, mission_id, &hunter, "QmFeedback", &salt),
+        &Vec::new(&env),
+    );
+    let submission = client.get_submission(&mission_id, &hunter);
+    assert_eq!(submission.status, SubmissionStatus::Committed);
+    assert_eq!(submission.ipfs_cid, String::from_str(&env, ""));
+    assert_eq!(token_client.balance(&hunter), 990);
+
+    // The CID cannot be revealed or swapped while submissions are open
+    let result = client.try_reveal_submission(&mission_id, &hunter, &cid, &salt);
+    assert_eq!(result, Err(Ok(QuidError::OutsideRevealWindow)));
+    let result = client.try_update_submission(&mission_id, &hunter, &cid);
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+
+    let wrong_salt = Bytes::from_array(&env, &[8; 16]);
+    let result = client.try_reveal_submission(&mission_id, &hunter, &cid, &wrong_salt);
+    assert_eq!(result, Err(Ok(QuidError::InvalidReveal)));
+
+    client.reveal_submission(&mission_id, &hunter, &cid, &salt);
+    let submission = client.get_submission(&mission_id, &hunter);
+    assert_eq!(submission.status, SubmissionStatus::Pending);
+    assert_eq!(submission.ipfs_cid, cid);
+
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&hunter), 1100);
+}
+
+#[test]
+fn test_copied_commitment_cannot_be_revealed() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    client.set_treasury(&Address::generate(&env));
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .commit_reveal()
+        .create(&client, &owner);
+
+    let hunter = Address::generate(&env);
+    let copycat = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &copycat, 1000);
+    let cid = String::from_str(&env, "QmFeedback");
+    let salt = Bytes::from_array(&env, &[7; 16]);
+
+    // The copycat replays the commitment it saw on chain
+    let commitment = commit_hash(&env, mission_id, &hunter, "QmFeedback", &salt);
+    client.commit_submission(&mission_id, &hunter, &commitment, &Vec::new(&env));
+    client.commit_submission(&mission_id, &copycat, &commitment, &Vec::new(&env));
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    client.reveal_submission(&mission_id, &hunter, &cid, &salt);
+
+    // Knowing the CID and salt is not enough: the hash is bound to the hunter
+    let result = client.try_reveal_submission(&mission_id, &copycat, &cid, &salt);
+    assert_eq!(result, Err(Ok(QuidError::InvalidReveal)));
+}
+
+#[test]
+fn test_sealed_commitment_cannot_be_slashed() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    client.set_treasury(&Address::generate(&env));
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .commit_reveal()
+        .create(&client, &owner);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    let salt = Bytes::from_array(&env, &[3; 16]);
+    client.commit_submission(
+        &mission_id,
+        &hunter,
+        &commit_hash(&env, mission_id, &hunter, "QmFeedback", &salt),
+        &Vec::new(&env),
+    );
+
+    // The owner has seen nothing but a hash, so there is no spam to punish
+    let reason = String::from_str(&env, "QmReason");
+    let result = client.try_slash_hunter_stake(&mission_id, &owner, &hunter, &reason);
+    assert_eq!(result, Err(Ok(QuidError::NotPending)));
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Committed
+    );
+
+    // Once revealed, the CID can be judged and slashed
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    let cid = String::from_str(&env, "QmFeedback");
+    client.reveal_submission(&mission_id, &hunter, &cid, &salt);
+    client.slash_hunter_stake(&mission_id, &owner, &hunter, &reason);
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Slashed
+    );
+}
+
+#[test]
+fn test_unrevealed_commitment_forfeits_stake() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .commit_reveal()
+        .create(&client, &owner);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    let salt = Bytes::from_array(&env, &[1; 16]);
+    client.commit_submission(
+        &mission_id,
+        &hunter,
+        &commit_hash(&env, mission_id, &hunter, "QmFeedback", &salt),
+        &Vec::new(&env),
+    );
+
+    let result = client.try_forfeit_unrevealed(&mission_id, &hunter);
+    assert_eq!(result, Err(Ok(QuidError::DeadlineNotReached)));
+
+    env.ledger()
+        .with_mut(|li| li.timestamp += WEEK + 2 * DAY + 1);
+
+    let cid = String::from_str(&env, "QmFeedback");
+    let result = client.try_reveal_submission(&mission_id, &hunter, &cid, &salt);
+    assert_eq!(result, Err(Ok(QuidError::OutsideRevealWindow)));
+
+    client.forfeit_unrevealed(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&treasury), 10);
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Expired
+    );
+}
+
+#[test]
+fn test_cancel_refunds_unrevealed_commitments_before_deadline() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    client.set_treasury(&Address::generate(&env));
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .commit_reveal()
+        .create(&client, &owner);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    let salt = Bytes::from_array(&env, &[2; 16]);
+    client.commit_submission(
+        &mission_id,
+        &hunter,
+        &commit_hash(&env, mission_id, &hunter, "QmFeedback", &salt),
+        &Vec::new(&env),
+    );
+
+    client.cancel_mission(&mission_id, &owner);
+    client.refund_pending(&mission_id, &0, &10);
+    assert_eq!(token_client.balance(&hunter), 1000);
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Expired
+    );
+}
+
+#[test]
+fn test_commit_reveal_mission_validation() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    // Forfeited stakes need somewhere to go
+    let result = MissionBuilder::new(&env, &token_address)
+        .commit_reveal()
+        .try_create(&client, &owner);
+    assert_eq!(result, Err(Ok(QuidError::TreasuryNotSet)));
+
+    client.set_treasury(&Address::generate(&env));
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+    let hunter = Address::generate(&env);
+    let result = client.try_commit_submission(
+        &mission_id,
+        &hunter,
+        &BytesN::from_array(&env, &[0; 32]),
+        &Vec::new(&env),
+    );
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+}
+
+#[test]
+fn test_withdraw_submission_refunds_stake() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    let cid = String::from_str(&env, "QmOops");
+    client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+    assert_eq!(token_client.balance(&hunter), 990);
+
+    client.withdraw_submission(&mission_id, &hunter);
+    let events = env.events().all();
+    let event = events.last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("sub"), Symbol::new(&env, "withdraw")).into_val(&env)
+    );
+
+    assert_eq!(token_client.balance(&hunter), 1000);
+    assert_eq!(
+        client.try_get_submission(&mission_id, &hunter),
+        Err(Ok(QuidError::SubmissionNotFound))
+    );
+    assert_eq!(client.list_submissions(&mission_id, &0, &10).len(), 0);
+
+    // Resubmitting reuses the hunter's index slot
+    client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+    assert_eq!(client.get_submission_count(&mission_id), 1);
+    assert_eq!(client.list_submissions(&mission_id, &0, &10).len(), 1);
+}
+
+#[test]
+fn test_withdraw_submission_pays_withdrawal_fee() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    client.set_fee_config(&FeeConfig {
+        fee_bps: 0,
+        max_fee: 0,
+        mode: FeeMode::OnFunding,
+        withdrawal_fee_bps: 1_000,
+    });
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmOops"),
+        &Vec::new(&env),
+    );
+
+    client.withdraw_submission(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&hunter), 999);
+    assert_eq!(token_client.balance(&treasury), 1);
+}
+
+#[test]
+fn test_withdraw_submission_while_paused() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    let cid = String::from_str(&env, "QmFeedback");
+    client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+
+    // The owner cannot hold a stake hostage by pausing
+    client.pause_mission(&mission_id, &owner);
+    client.withdraw_submission(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&hunter), 1000);
+}
+
+#[test]
+fn test_withdraw_submission_rejected_once_reviewed() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let cid = String::from_str(&env, "QmFeedback");
+    let winner = Address::generate(&env);
+    let late = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &winner, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &late, 1000);
+    client.submit_feedback(&mission_id, &winner, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &late, &cid, &Vec::new(&env));
+
+    client.payout_participant(&mission_id, &owner, &winner, &None);
+    client.claim_reward(&mission_id, &winner);
+    let result = client.try_withdraw_submission(&mission_id, &winner);
+    assert_eq!(result, Err(Ok(QuidError::NotPending)));
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    let result = client.try_withdraw_submission(&mission_id, &late);
+    assert_eq!(result, Err(Ok(QuidError::DeadlinePassed)));
+}
+
+fn submit_raffle_entries(
+    env: &Env,
+    client: &QuidStoreContractClient,
+    token_address: &Address,
+    mission_id: u64,
+    count: usize,
+) -> Vec<Address> {
+    let mut hunters = Vec::new(env);
+    for _ in 0..count {
+        let hunter = Address::generate(env);
+        mint_tokens_for_hunter(env, token_address, &hunter, 1000);
+        client.submit_feedback(
+            &mission_id,
+            &hunter,
+            &String::from_str(env, "QmEntry"),
+            &Vec::new(env),
+        );
+        hunters.push_back(hunter);
+    }
+    hunters
+}
+
+#[test]
+fn test_raffle_draw_awards_prizes_and_refunds_stakes() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .raffle()
+        .slots(2)
+        .create(&client, &owner);
+    let hunters = submit_raffle_entries(&env, &client, &token_address, mission_id, 5);
+
+    // The last hunter is never marked eligible
+    let eligible = hunters.slice(0..4);
+    client.mark_eligible(&mission_id, &owner, &eligible);
+    assert_eq!(client.get_raffle_entrants(&mission_id), eligible);
+
+    let result =
+        client.try_payout_participant(&mission_id, &owner, &hunters.get(0).unwrap(), &None);
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+    let result = client.try_draw_winners(&mission_id, &owner);
+    assert_eq!(result, Err(Ok(QuidError::DeadlineNotReached)));
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    let draw = client.draw_winners(&mission_id, &owner);
+
+    assert_eq!(draw.winners.len(), 2);
+    assert_eq!(draw.entrants, eligible);
+    assert_eq!(client.get_raffle_draw(&mission_id), Some(draw.clone()));
+    assert_eq!(
+        client.get_mission(&mission_id).status,
+        MissionStatus::Completed
+    );
+    assert_eq!(client.refund_pending(&mission_id, &0, &10), 0);
+
+    for hunter in hunters.iter() {
+        let won = draw.winners.contains(&hunter);
+        // Winners recover their stake through the claim
+        let balance = if won { 990 } else { 1000 };
+        assert_eq!(token_client.balance(&hunter), balance);
+        assert!(!won || eligible.contains(&hunter));
+        let expected = if won {
+            SubmissionStatus::Approved
+        } else {
+            SubmissionStatus::Expired
+        };
+        assert_eq!(client.get_submission(&mission_id, &hunter).status, expected);
+    }
+
+    for winner in draw.winners.iter() {
+        assert_eq!(client.claim_reward(&mission_id, &winner), 100);
+        assert_eq!(token_client.balance(&winner), 1100);
+    }
+    assert_eq!(token_client.balance(&contract_id), 0);
+
+    let result = client.try_draw_winners(&mission_id, &owner);
+    assert_eq!(result, Err(Ok(QuidError::MissionClosed)));
+}
+
+#[test]
+fn test_raffle_refunds_unawarded_prizes() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let owner_balance_before = token_client.balance(&owner);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .raffle()
+        .slots(3)
+        .create(&client, &owner);
+    let hunters = submit_raffle_entries(&env, &client, &token_address, mission_id, 1);
+    client.mark_eligible(&mission_id, &owner, &hunters);
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    let draw = client.draw_winners(&mission_id, &owner);
+
+    assert_eq!(draw.winners, hunters);
+    assert_eq!(token_client.balance(&owner), owner_balance_before - 100);
+}
+
+#[test]
+fn test_expire_after_draw_does_not_refund_twice() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let owner_balance_before = token_client.balance(&owner);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .raffle()
+        .slots(5)
+        .create(&client, &owner);
+    let hunters = submit_raffle_entries(&env, &client, &token_address, mission_id, 1);
+    client.mark_eligible(&mission_id, &owner, &hunters);
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    client.draw_winners(&mission_id, &owner);
+    assert_eq!(token_client.balance(&owner), owner_balance_before - 100);
+
+    // The draw already returned the four unawarded prizes
+    env.ledger().with_mut(|li| li.timestamp += 2 * WEEK);
+    let result = client.try_expire_mission(&mission_id);
+    assert_eq!(result, Err(Ok(QuidError::MissionClosed)));
+    assert_eq!(token_client.balance(&owner), owner_balance_before - 100);
+
+    client.claim_reward(&mission_id, &hunters.get(0).unwrap());
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_raffle_prizes_capped() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let result = MissionBuilder::new(&env, &token_address)
+        .raffle()
+        .slots(MAX_RAFFLE_WINNERS + 1)
+        .no_stake()
+        .try_create(&client, &owner);
+    assert_eq!(result, Err(Ok(QuidError::BatchTooLarge)));
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .raffle()
+        .slots(MAX_RAFFLE_WINNERS)
+        .create(&client, &owner);
+    let result = client.try_fund_mission(&mission_id, &1);
+    assert_eq!(result, Err(Ok(QuidError::BatchTooLarge)));
+}
+
+#[test]
+fn test_full_raffle_draw_fits_in_transaction() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    // Payout fees add a treasury transfer per winner
+    client.set_treasury(&Address::generate(&env));
+    client.set_fee_config(&FeeConfig {
+        fee_bps: 100,
+        max_fee: 0,
+        mode: FeeMode::OnPayout,
+        withdrawal_fee_bps: 0,
+    });
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .raffle()
+        .slots(MAX_RAFFLE_WINNERS)
+        .create(&client, &owner);
+    let hunters = submit_raffle_entries(
+        &env,
+        &client,
+        &token_address,
+        mission_id,
+        MAX_RAFFLE_ENTRANTS as usize,
+    );
+    for start in (0..MAX_RAFFLE_ENTRANTS).step_by(MAX_BATCH_SIZE as usize) {
+        client.mark_eligible(
+            &mission_id,
+            &owner,
+            &hunters.slice(start..start + MAX_BATCH_SIZE),
+        );
+    }
+    let result = client.try_mark_eligible(
+        &mission_id,
+        &owner,
+        &submit_raffle_entries(&env, &client, &token_address, mission_id, 1),
+    );
+    assert_eq!(
+        result.unwrap().unwrap().get(0).unwrap().error,
+        Some(QuidError::BatchTooLarge as u32)
+    );
+
+    // Worst case: every entrant but the winners withdrew, so the draw reads
+    // all of them and still approves a full set of prizes
+    for hunter in hunters
+        .slice(MAX_RAFFLE_WINNERS..MAX_RAFFLE_ENTRANTS)
+        .iter()
+    {
+        client.withdraw_submission(&mission_id, &hunter);
+    }
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    let draw = client.draw_winners(&mission_id, &owner);
+    assert_fits_in_transaction(&env);
+    assert_eq!(draw.winners.len(), MAX_RAFFLE_WINNERS);
+}
+
+#[test]
+fn test_mark_eligible_reports_per_hunter() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .raffle()
+        .slots(2)
+        .create(&client, &owner);
+    let hunters = submit_raffle_entries(&env, &client, &token_address, mission_id, 1);
+    let hunter = hunters.get(0).unwrap();
+    let stranger = Address::generate(&env);
+
+    let results = client.mark_eligible(
+        &mission_id,
+        &owner,
+        &Vec::from_array(&env, [hunter.clone(), hunter.clone(), stranger.clone()]),
+    );
+    assert_eq!(results.get(0).unwrap().error, None);
+    assert_eq!(
+        results.get(1).unwrap().error,
+        Some(QuidError::InvalidState as u32)
+    );
+    assert_eq!(
+        results.get(2).unwrap().error,
+        Some(QuidError::SubmissionNotFound as u32)
+    );
+    assert_eq!(client.get_raffle_entrants(&mission_id).len(), 1);
+
+    let standard = create_basic_mission(&env, &client, &owner, &token_address, 5);
+    let result = client.try_mark_eligible(&standard, &owner, &hunters);
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+    let result = client.try_draw_winners(&standard, &owner);
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+}
+
+#[test]
+fn test_marked_entrant_cannot_update_submission() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .raffle()
+        .slots(2)
+        .create(&client, &owner);
+    let hunters = submit_raffle_entries(&env, &client, &token_address, mission_id, 2);
+    let marked = hunters.get(0).unwrap();
+    let unmarked = hunters.get(1).unwrap();
+    client.mark_eligible(
+        &mission_id,
+        &owner,
+        &Vec::from_array(&env, [marked.clone()]),
+    );
+
+    let new_cid = String::from_str(&env, "QmSwapped");
+    let result = client.try_update_submission(&mission_id, &marked, &new_cid);
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+    let submission = client.get_submission(&mission_id, &marked);
+    assert_eq!(submission.ipfs_cid, String::from_str(&env, "QmEntry"));
+
+    // Entries not yet judged can still be revised
+    client.update_submission(&mission_id, &unmarked, &new_cid);
+}
+
+#[test]
+fn test_withdrawn_entrant_must_be_marked_again() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .raffle()
+        .slots(1)
+        .create(&client, &owner);
+    let hunters = submit_raffle_entries(&env, &client, &token_address, mission_id, 1);
+    let hunter = hunters.get(0).unwrap();
+    client.mark_eligible(&mission_id, &owner, &hunters);
+
+    // Withdrawing and resubmitting must not carry the judged entry over
+    client.withdraw_submission(&mission_id, &hunter);
+    assert!(client.get_raffle_entrants(&mission_id).is_empty());
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmSpam"),
+        &Vec::new(&env),
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += WEEK + 1);
+    let draw = client.draw_winners(&mission_id, &owner);
+    assert!(draw.winners.is_empty());
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Pending
+    );
+}
+
+#[test]
+fn test_admin_set_at_deploy() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(QuidStoreContract, (admin.clone(),));
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_admin_rotation_requires_accept() {
+    let (env, contract_id, _, _) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let admin = client.get_admin();
+    let new_admin = Address::generate(&env);
+
+    assert_eq!(client.try_accept_admin(), Err(Ok(QuidError::InvalidState)));
+
+    client.propose_admin(&new_admin);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_admin_actions_require_admin_auth() {
+    let (env, contract_id, _, _) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+
+    let admin = client.get_admin();
+    client.pause();
+    assert_eq!(env.auths()[0].0, admin);
+
+    // Protocol configuration belongs to the admin, not the treasury
+    client.set_treasury(&Address::generate(&env));
+    client.set_fee_config(&FeeConfig {
+        fee_bps: 100,
+        max_fee: 0,
+        mode: FeeMode::OnFunding,
+        withdrawal_fee_bps: 0,
+    });
+    assert_eq!(env.auths()[0].0, admin);
+    client.set_fee_exempt(&Address::generate(&env), &true);
+    assert_eq!(env.auths()[0].0, admin);
+    client.set_arbiter(&Address::generate(&env));
+    assert_eq!(env.auths()[0].0, admin);
+    client.set_reputation_contract(&Address::generate(&env));
+    assert_eq!(env.auths()[0].0, admin);
+}
+
+#[test]
+fn test_global_pause_blocks_new_activity_but_allows_refunds() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    let admin = client.get_admin();
+
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+    let cid = String::from_str(&env, "QmFeedback");
+    let hunter = Address::generate(&env);
+    let withdrawing = Address::generate(&env);
+    let newcomer = Address::generate(&env);
+    for account in [&hunter, &withdrawing, &newcomer] {
+        mint_tokens_for_hunter(&env, &token_address, account, 1000);
+    }
+    client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &withdrawing, &cid, &Vec::new(&env));
+
+    client.pause();
+    assert_eq!(env.auths()[0].0, admin);
+    assert!(client.is_paused());
+
+    let result = client.try_submit_feedback(&mission_id, &newcomer, &cid, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(QuidError::ContractPaused)));
+    let result = client.try_payout_participant(&mission_id, &owner, &hunter, &None);
+    assert_eq!(result, Err(Ok(QuidError::ContractPaused)));
+    let result = client.try_fund_mission(&mission_id, &1);
+    assert_eq!(result, Err(Ok(QuidError::ContractPaused)));
+
+    // Funds can still go back to their owners
+    client.withdraw_submission(&mission_id, &withdrawing);
+    assert_eq!(token_client.balance(&withdrawing), 1000);
+    client.cancel_mission(&mission_id, &owner);
+    client.refund_pending(&mission_id, &0, &10);
+    assert_eq!(token_client.balance(&hunter), 1000);
+
+    client.unpause();
+    assert!(!client.is_paused());
+    create_basic_mission(&env, &client, &owner, &token_address, 5);
+}
+
+/// Write missions the way the contract stored them before schema versioning,
+/// with their escrow already held by the contract. The instance is left as an
+/// upgraded pre-versioning deployment would be, with no schema version and no
+/// admin.
+fn write_legacy_missions(
+    env: &Env,
+    contract_id: &Address,
+    owner: &Address,
+    token_address: &Address,
+    count: u64,
+) {
+    env.as_contract(contract_id, || {
+        for mission_id in 1..=count {
+            let mission = MissionV1 {
+                id: mission_id,
+                owner: owner.clone(),
+                title: String::from_str(env, "Legacy"),
+                description_cid: String::from_str(env, "QmDesc"),
+                reward_token: token_address.clone(),
+                reward_amount: 100,
+                max_participants: 2,
+                participants_count: 0,
+                status: MissionStatus::Open,
+                created_at: env.ledger().timestamp(),
+                min_asset: None,
+                min_asset_amount: 0,
+            };
+            env.storage()
+                .persistent()
+                .set(&DataKey::Mission(mission_id), &mission);
+        }
+        env.storage().instance().set(&DataKey::MissionCount, &count);
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+        env.storage().instance().remove(&DataKey::Admin);
+    });
+    mint_tokens_for_hunter(env, token_address, contract_id, 200 * count as i128);
+}
+
+#[test]
+fn test_legacy_entries_read_through_current_schema() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    write_legacy_missions(&env, &contract_id, &owner, &token_address, 1);
+
+    // A hunter who staked under the old code
+    let hunter = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        let submission = SubmissionV1 {
+            hunter: hunter.clone(),
+            ipfs_cid: String::from_str(&env, "QmLegacy"),
+            status: SubmissionStatus::Pending,
+            submitted_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Submission(1, hunter.clone()), &submission);
+        env.storage()
+            .persistent()
+            .set(&DataKey::HunterStake(1, hunter.clone()), &10i128);
+    });
+    mint_tokens_for_hunter(&env, &token_address, &contract_id, 10);
+
+    let mission = client.get_mission(&1);
+    assert_eq!(mission.reward_amount, 100);
+    assert_eq!(mission.stake_token, None);
+    assert_eq!(mission.submission_deadline, u64::MAX);
+    assert_eq!(mission.allowlist, Allowlist::Open);
+    assert_eq!(client.get_submission(&1, &hunter).commitment, None);
+
+    // The stake token was never recorded, so only the reward is paid out
+    client.payout_participant(&1, &owner, &hunter, &None);
+    client.claim_reward(&1, &hunter);
+    assert_eq!(token_client.balance(&hunter), 100);
+
+    // The unpaid slot was recorded as escrow the first time the mission moved funds
+    assert_eq!(client.get_escrow(&1, &token_address), 100);
+    assert_eq!(client.get_liabilities(&token_address), 100);
+    assert!(client.check_solvency(&token_address));
+
+    // Written back in the current schema
+    env.as_contract(&contract_id, || {
+        let mission: VersionedMission = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Mission(1))
+            .unwrap();
+        assert!(mission.is_current());
+        let submission: VersionedSubmission = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Submission(1, hunter.clone()))
+            .unwrap();
+        assert!(matches!(submission, VersionedSubmission::V2(_)));
+    });
+}
+
+#[test]
+fn test_legacy_stake_settled_by_admin() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    write_legacy_missions(&env, &contract_id, &owner, &token_address, 1);
+    client.set_treasury(&Address::generate(&env));
+    client.bootstrap_admin(&Address::generate(&env));
+
+    // The hunter staked a token of their choosing under the old code
+    let stake_admin = Address::generate(&env);
+    let stake_token = env
+        .register_stellar_asset_contract_v2(stake_admin)
+        .address();
+    let hunter = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::HunterStake(1, hunter.clone()), &10i128);
+    });
+    mint_tokens_for_hunter(&env, &stake_token, &contract_id, 10);
+
+    // Cancelling never touches the frozen stake
+    client.cancel_mission(&1, &owner);
+    let result =
+        client.try_slash_hunter_stake(&1, &owner, &hunter, &String::from_str(&env, "QmReason"));
+    assert_eq!(result, Err(Ok(QuidError::StakeNotFound)));
+
+    assert_eq!(client.settle_legacy_stake(&1, &hunter, &stake_token), 10);
+    assert_eq!(env.auths()[0].0, client.get_admin());
+    assert_eq!(TokenClient::new(&env, &stake_token).balance(&hunter), 10);
+    let result = client.try_settle_legacy_stake(&1, &hunter, &stake_token);
+    assert_eq!(result, Err(Ok(QuidError::StakeNotFound)));
+
+    // Current missions record their stake token and settle on their own
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+    let result = client.try_settle_legacy_stake(&mission_id, &hunter, &token_address);
+    assert_eq!(result, Err(Ok(QuidError::InvalidState)));
+}
+
+#[test]
+fn test_legacy_stake_cannot_drain_mission_escrow() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    write_legacy_missions(&env, &contract_id, &owner, &token_address, 1);
+    client.set_treasury(&Address::generate(&env));
+    client.bootstrap_admin(&Address::generate(&env));
+    client.cancel_mission(&1, &owner);
+
+    // The stake was sent in another token, which the contract still holds
+    let stake_admin = Address::generate(&env);
+    let stake_token = env
+        .register_stellar_asset_contract_v2(stake_admin)
+        .address();
+    let hunter = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::HunterStake(1, hunter.clone()), &10i128);
+    });
+    mint_tokens_for_hunter(&env, &stake_token, &contract_id, 10);
+
+    // Naming the reward token of an active mission would pay from its escrow
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+    let escrow = client.get_escrow(&mission_id, &token_address);
+    let result = client.try_settle_legacy_stake(&1, &hunter, &token_address);
+    assert_eq!(result, Err(Ok(QuidError::InsufficientFunds)));
+    assert_eq!(token_client.balance(&hunter), 0);
+    assert_eq!(client.get_escrow(&mission_id, &token_address), escrow);
+    assert_solvent(&env, &client, &contract_id, &token_address);
+
+    // The stake is still recorded and settles in the right token
+    assert_eq!(client.settle_legacy_stake(&1, &hunter, &stake_token), 10);
+    assert_eq!(TokenClient::new(&env, &stake_token).balance(&hunter), 10);
+}
+
+#[test]
+fn test_migrate_rewrites_legacy_missions_in_batches() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    // A fresh deployment starts on the current schema
+    assert_eq!(client.get_schema_version(), 2);
+    write_legacy_missions(&env, &contract_id, &owner, &token_address, 3);
+
+    // The upgraded instance has no admin until the treasury appoints one
+    assert_eq!(client.try_migrate(&2), Err(Ok(QuidError::NotInitialized)));
+    let treasury = Address::generate(&env);
+    let admin = Address::generate(&env);
+    client.set_treasury(&treasury);
+    client.bootstrap_admin(&admin);
+    assert_eq!(env.auths()[0].0, treasury);
+    assert_eq!(client.get_admin(), admin);
+    let result = client.try_bootstrap_admin(&treasury);
+    assert_eq!(result, Err(Ok(QuidError::AlreadyInitialized)));
+
+    assert_eq!(client.get_schema_version(), 1);
+    assert_eq!(client.get_liabilities(&token_address), 0);
+    assert_eq!(client.migrate(&2), 1);
+    assert_eq!(client.get_schema_version(), 1);
+
+    // Escrow held before it was tracked is recorded as each mission migrates
+    assert_eq!(client.get_escrow(&2, &token_address), 200);
+    assert_eq!(client.get_escrow(&3, &token_address), 0);
+    assert_eq!(client.get_liabilities(&token_address), 400);
+
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert!(storage
+            .get::<_, VersionedMission>(&DataKey::Mission(2))
+            .is_some_and(|mission| mission.is_current()));
+        assert!(storage.get::<_, MissionV1>(&DataKey::Mission(3)).is_some());
+    });
+
+    assert_eq!(client.migrate(&2), 0);
+    assert_eq!(client.get_schema_version(), 2);
+    assert_eq!(client.get_liabilities(&token_address), 600);
+    assert_solvent(&env, &client, &contract_id, &token_address);
+
+    client.cancel_mission(&3, &owner);
+    assert_eq!(client.get_escrow(&3, &token_address), 0);
+    assert_solvent(&env, &client, &contract_id, &token_address);
+    assert_eq!(
+        client.get_mission(&3).title,
+        String::from_str(&env, "Legacy")
+    );
+
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+    assert_eq!(mission_id, 4);
+}
+
+#[test]
+fn test_writes_extend_ttls() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+
+    env.as_contract(&contract_id, || {
+        let storage = env.storage();
+        assert_eq!(storage.instance().get_ttl(), BUMP_AMOUNT);
+        for key in [
+            DataKey::Mission(mission_id),
+            DataKey::Submission(mission_id, hunter.clone()),
+            DataKey::HunterStake(mission_id, hunter.clone()),
+            DataKey::SubmissionIndex(mission_id, 0),
+        ] {
+            assert_eq!(storage.persistent().get_ttl(&key), BUMP_AMOUNT);
+        }
+    });
+}
+
+#[test]
+fn test_bump_mission_keeps_campaign_alive() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let token_client = TokenClient::new(&env, &token_address);
+    client.set_treasury(&Address::generate(&env));
+    client.set_token_vetted(&token_address, &true);
+    client.set_fee_exempt(&owner, &true);
+    let idle_mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+    let reviewer = Address::generate(&env);
+    client.add_reviewer(&mission_id, &owner, &reviewer, &MissionRole::Reviewer);
+    let hunter = Address::generate(&env);
+    let quitter = Address::generate(&env);
+    let cid = String::from_str(&env, "QmFeedback");
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    mint_tokens_for_hunter(&env, &token_address, &quitter, 1000);
+    client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+    client.submit_feedback(&mission_id, &quitter, &cid, &Vec::new(&env));
+    client.withdraw_submission(&mission_id, &quitter);
+
+    // Past the threshold, so the bump extends every entry again
+    let elapsed = LIFETIME_THRESHOLD + 1;
+    env.ledger().with_mut(|li| li.sequence_number += elapsed);
+    assert_eq!(client.bump_mission(&mission_id, &0, &10), 0);
+
+    env.as_contract(&contract_id, || {
+        let storage = env.storage();
+        assert_eq!(storage.instance().get_ttl(), BUMP_AMOUNT);
+        for key in [
+            DataKey::Mission(mission_id),
+            DataKey::Submission(mission_id, hunter.clone()),
+            DataKey::HunterStake(mission_id, hunter.clone()),
+            DataKey::SubmissionIndex(mission_id, 0),
+            DataKey::SubmissionCount(mission_id),
+            DataKey::OpenSubmissions(mission_id),
+            DataKey::Withdrawn(mission_id, quitter.clone()),
+            DataKey::OwnerMission(owner.clone(), 1),
+            DataKey::OwnerMissionCount(owner.clone()),
+            DataKey::Liabilities(token_address.clone()),
+            DataKey::Reviewers(mission_id),
+            DataKey::Reviewer(mission_id, reviewer.clone()),
+            DataKey::VettedToken(token_address.clone()),
+            DataKey::FeeExempt(owner.clone()),
+        ] {
+            assert_eq!(storage.persistent().get_ttl(&key), BUMP_AMOUNT);
+        }
+        assert_eq!(
+            storage
+                .persistent()
+                .get_ttl(&DataKey::OwnerMission(owner.clone(), 0)),
+            BUMP_AMOUNT - elapsed
+        );
+        assert_eq!(
+            storage
+                .persistent()
+                .get_ttl(&DataKey::Mission(idle_mission_id)),
+            BUMP_AMOUNT - elapsed
+        );
+    });
+
+    // Outlive the original TTL; the bumped campaign settles as usual
+    env.ledger()
+        .with_mut(|li| li.sequence_number += BUMP_AMOUNT - elapsed + 1);
+    assert_eq!(
+        client.get_submission(&mission_id, &hunter).status,
+        SubmissionStatus::Pending
+    );
+    assert_eq!(
+        client.get_role(&mission_id, &reviewer),
+        Some(MissionRole::Reviewer)
+    );
+    client.payout_participant(&mission_id, &reviewer, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+    assert_eq!(token_client.balance(&hunter), 1100);
+    let owned = client.list_missions_by_owner(&owner, &0, &10, &None);
+    assert_eq!(owned.len(), 2);
+    assert_eq!(owned.get(1).unwrap().id, mission_id);
+}
+
+#[test]
+fn test_bump_mission_pages_through_submissions() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 5);
+
+    let cid = String::from_str(&env, "QmFeedback");
+    for _ in 0..25 {
+        let hunter = Address::generate(&env);
+        mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+        client.submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+    }
+
+    let mut cursor = 0;
+    loop {
+        let remaining = client.bump_mission(&mission_id, &cursor, &u32::MAX);
+        assert_fits_in_transaction(&env);
+        cursor += MAX_BATCH_SIZE;
+        if remaining == 0 {
+            break;
+        }
+    }
+    assert_eq!(cursor, 30);
+}
+
+/// xorshift64: enough to drive reproducible random operation sequences.
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, bound: u32) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % bound as u64) as u32
+    }
+}
+
+fn assert_solvent(
+    env: &Env,
+    client: &QuidStoreContractClient,
+    contract_id: &Address,
+    token: &Address,
+) {
+    let balance = TokenClient::new(env, token).balance(contract_id);
+    assert_eq!(client.get_liabilities(token), balance);
+    assert!(client.check_solvency(token));
+}
+
+#[test]
+fn test_liabilities_follow_escrow() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let mission_id = create_basic_mission(&env, &client, &owner, &token_address, 3);
+    assert_eq!(client.get_liabilities(&token_address), 300);
+
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+    assert_eq!(client.get_liabilities(&token_address), 310);
+
+    client.payout_participant(&mission_id, &owner, &hunter, &None);
+    client.claim_reward(&mission_id, &hunter);
+    assert_eq!(client.get_liabilities(&token_address), 200);
+
+    client.cancel_mission(&mission_id, &owner);
+    assert_eq!(client.get_liabilities(&token_address), 0);
+    assert_solvent(&env, &client, &contract_id, &token_address);
+}
+
+/// What a mission owes, recomputed from its public state rather than the
+/// contract's own bookkeeping: unpaid reward slots of a live mission, stakes of
+/// open submissions, approved claims and slashes still under dispute.
+fn expected_escrow(
+    client: &QuidStoreContractClient,
+    mission_id: u64,
+    hunters: &Vec<Address>,
+) -> i128 {
+    let mission = client.get_mission(&mission_id);
+    let mut owed = 0;
+    if !mission.status.is_closed() {
+        owed +=
+            (mission.max_participants - mission.participants_count) as i128 * mission.reward_amount;
+    }
+    for hunter in hunters.iter() {
+        if let Ok(Ok(submission)) = client.try_get_submission(&mission_id, &hunter) {
+            if submission.status.is_open() {
+                owed += mission.stake_amount;
+            }
+        }
+        if let Some(claimable) = client.get_claimable(&mission_id, &hunter) {
+            owed += claimable.amount + claimable.stake_amount;
+        }
+        if let Ok(Ok(record)) = client.try_get_slash(&mission_id, &hunter) {
+            if matches!(record.status, SlashStatus::Pending | SlashStatus::Appealed) {
+                owed += record.amount;
+            }
+        }
+    }
+    owed
+}
+
+#[test]
+fn test_solvency_holds_across_random_operations() {
+    let mut completed = 0;
+    let mut drawn = 0;
+    for seed in 1..=12u64 {
+        let (env, contract_id, owner, token_address) = setup_test_env();
+        let client = QuidStoreContractClient::new(&env, &contract_id);
+        let treasury = Address::generate(&env);
+        client.set_treasury(&treasury);
+        let mode = if seed % 2 == 0 {
+            FeeMode::OnPayout
+        } else {
+            FeeMode::OnFunding
+        };
+        client.set_fee_config(&FeeConfig {
+            fee_bps: 250,
+            max_fee: 0,
+            mode,
+            withdrawal_fee_bps: 500,
+        });
+
+        let mut hunters = Vec::new(&env);
+        for _ in 0..4 {
+            let hunter = Address::generate(&env);
+            mint_tokens_for_hunter(&env, &token_address, &hunter, 10_000);
+            hunters.push_back(hunter);
+        }
+        let reason = String::from_str(&env, "QmReason");
+        let cid = String::from_str(&env, "QmFeedback");
+
+        let mut rng = Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        let mut missions: Vec<u64> = Vec::new(&env);
+        for _ in 0..80 {
+            let op = rng.below(12);
+            if op == 0 || missions.is_empty() {
+                let slots = 1 + rng.below(3);
+                let mission_id = if rng.below(3) == 0 {
+                    MissionBuilder::new(&env, &token_address)
+                        .raffle()
+                        .slots(slots)
+                        .create(&client, &owner)
+                } else {
+                    create_basic_mission(&env, &client, &owner, &token_address, slots)
+                };
+                missions.push_back(mission_id);
+            } else {
+                let mission_id = missions.get(rng.below(missions.len())).unwrap();
+                let hunter = hunters.get(rng.below(hunters.len())).unwrap();
+                match op {
+                    1 => {
+                        let _ =
+                            client.try_submit_feedback(&mission_id, &hunter, &cid, &Vec::new(&env));
+                    }
+                    2 => {
+                        let _ = client.try_payout_participant(&mission_id, &owner, &hunter, &None);
+                    }
+                    3 => {
+                        let _ =
+                            client.try_slash_hunter_stake(&mission_id, &owner, &hunter, &reason);
+                    }
+                    4 => {
+                        let _ = client.try_cancel_mission(&mission_id, &owner);
+                    }
+                    5 => {
+                        let _ = client.try_withdraw_submission(&mission_id, &hunter);
+                    }
+                    6 => {
+                        let _ = client.try_approve_submission(&mission_id, &owner, &hunter, &None);
+                        let _ = client.try_claim_reward(&mission_id, &hunter);
+                    }
+                    7 => {
+                        env.ledger().with_mut(|li| li.timestamp += 2 * DAY);
+                        let _ = client.try_finalize_slash(&mission_id, &hunter);
+                        let _ = client.try_expire_mission(&mission_id);
+                    }
+                    8 => {
+                        let result = client.try_update_mission_status(
+                            &mission_id,
+                            &owner,
+                            &MissionStatus::Completed,
+                        );
+                        completed += result.is_ok() as u32;
+                    }
+                    9 => {
+                        let _ = client.try_mark_eligible(
+                            &mission_id,
+                            &owner,
+                            &Vec::from_array(&env, [hunter.clone()]),
+                        );
+                        drawn += client.try_draw_winners(&mission_id, &owner).is_ok() as u32;
+                    }
+                    10 => {
+                        let _ = client.try_refund_pending(&mission_id, &0, &MAX_BATCH_SIZE);
+                    }
+                    _ => {
+                        let _ = client.try_claim_reward(&mission_id, &hunter);
+                    }
+                }
+            }
+
+            let mut owed = 0;
+            for mission_id in missions.iter() {
+                let expected = expected_escrow(&client, mission_id, &hunters);
+                assert_eq!(client.get_escrow(&mission_id, &token_address), expected);
+                owed += expected;
+            }
+            assert_eq!(client.get_liabilities(&token_address), owed);
+            assert_solvent(&env, &client, &contract_id, &token_address);
+        }
+    }
+    assert!(completed > 0 && drawn > 0);
+}
+
+/// Token that burns 1% of every transfer, standing in for fee-on-transfer assets.
+#[soroban_sdk::contract]
+struct FeeOnTransferToken;
+
+#[soroban_sdk::contractimpl]
+impl FeeOnTransferToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().instance().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().instance().get(&id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let from_balance = Self::balance(env.clone(), from.clone());
+        env.storage()
+            .instance()
+            .set(&from, &(from_balance - amount));
+        Self::mint(env, to, amount - amount / 100);
+    }
+}
+
+fn register_fee_on_transfer_token(env: &Env) -> (Address, FeeOnTransferTokenClient<'_>) {
+    let token_address = env.register(FeeOnTransferToken, ());
+    let client = FeeOnTransferTokenClient::new(env, &token_address);
+    (token_address, client)
+}
+
+#[test]
+fn test_fee_on_transfer_reward_token_rejected() {
+    let (env, contract_id, owner, _) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let (token_address, token_client) = register_fee_on_transfer_token(&env);
+    token_client.mint(&owner, &1_000_000);
+
+    let result = MissionBuilder::new(&env, &token_address)
+        .reward(1_000)
+        .no_stake()
+        .try_create(&client, &owner);
+
+    assert_eq!(result, Err(Ok(QuidError::TokenUnderDelivered)));
+    assert_eq!(token_client.balance(&owner), 1_000_000);
+    assert_eq!(client.get_liabilities(&token_address), 0);
+}
+
+#[test]
+fn test_fee_on_transfer_stake_token_rejected() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let (stake_token, stake_client) = register_fee_on_transfer_token(&env);
+
+    let mission_id = MissionBuilder::new(&env, &token_address)
+        .stake_token(Some(stake_token.clone()))
+        .stake_amount(1_000)
+        .create(&client, &owner);
+
+    let hunter = Address::generate(&env);
+    stake_client.mint(&hunter, &5_000);
+    let result = client.try_submit_feedback(
+        &mission_id,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+
+    assert_eq!(result, Err(Ok(QuidError::TokenUnderDelivered)));
+    assert_eq!(stake_client.balance(&hunter), 5_000);
+    assert_eq!(client.get_submission_count(&mission_id), 0);
+}
+
+#[test]
+fn test_token_allowlist_gates_new_missions() {
+    let (env, contract_id, owner, token_address) = setup_test_env();
+    let client = QuidStoreContractClient::new(&env, &contract_id);
+    let admin = client.get_admin();
+
+    // Off by default, so any token can back a mission
+    assert!(!client.is_token_allowlist_enabled());
+    let existing = create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    client.set_token_allowlist_enabled(&true);
+    assert_eq!(env.auths()[0].0, admin);
+    assert!(client.is_token_allowlist_enabled());
+
+    let result = MissionBuilder::new(&env, &token_address)
+        .slots(3)
+        .try_create(&client, &owner);
+    assert_eq!(result, Err(Ok(QuidError::TokenNotVetted)));
+
+    client.set_token_vetted(&token_address, &true);
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("token"), symbol_short!("vet")).into_val(&env)
+    );
+    assert!(client.is_token_vetted(&token_address));
+    create_basic_mission(&env, &client, &owner, &token_address, 3);
+
+    // Revoking blocks new missions but leaves escrowed ones untouched
+    client.set_token_vetted(&token_address, &false);
+    assert!(!client.is_token_vetted(&token_address));
+    let hunter = Address::generate(&env);
+    mint_tokens_for_hunter(&env, &token_address, &hunter, 1000);
+    client.submit_feedback(
+        &existing,
+        &hunter,
+        &String::from_str(&env, "QmFeedback"),
+        &Vec::new(&env),
+    );
+    client.payout_participant(&existing, &owner, &hunter, &None);
+    client.claim_reward(&existing, &hunter);
 }
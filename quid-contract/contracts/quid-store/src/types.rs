@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Copy)]
 #[contracttype]
@@ -10,6 +10,47 @@ pub enum MissionStatus {
     Paused,
     Completed,
     Cancelled,
+    Expired,
+}
+
+impl MissionStatus {
+    /// Terminal states: escrow has been settled and nothing can reopen them.
+    pub fn is_closed(self) -> bool {
+        matches!(
+            self,
+            MissionStatus::Completed | MissionStatus::Cancelled | MissionStatus::Expired
+        )
+    }
+
+    /// Owner-driven lifecycle: Created -> Open -> Started/Paused -> Completed/Cancelled.
+    /// `Expired` is only reachable through `expire_mission`.
+    pub fn can_transition_to(self, next: MissionStatus) -> bool {
+        use MissionStatus::*;
+        matches!(
+            (self, next),
+            (Created, Open)
+                | (Created, Cancelled)
+                | (Open, Started)
+                | (Open, Paused)
+                | (Started, Paused)
+                | (Paused, Open)
+                | (Paused, Started)
+                | (Open | Started | Paused, Completed)
+                | (Open | Started | Paused, Cancelled)
+        )
+    }
+}
+
+/// Delegated permissions on a mission, from least to most privileged.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Copy)]
+#[contracttype]
+pub enum MissionRole {
+    /// Approve, reject and pay out submissions.
+    Reviewer,
+    /// Reviewer rights plus slashing, pausing and status changes.
+    Manager,
+    /// Everything, including cancelling and managing reviewers.
+    Owner,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Copy)]
@@ -20,11 +61,65 @@ pub enum SubmissionStatus {
     Approved,
     Paid,
     Rejected,
+    Expired,
+    /// Only a commitment is stored; the CID is revealed after submissions close.
+    Committed,
+    /// The stake was slashed; the outcome is tracked by the `SlashRecord`.
+    Slashed,
+}
+
+impl SubmissionStatus {
+    /// Awaiting review or reveal, with the stake still escrowed.
+    pub fn is_open(self) -> bool {
+        matches!(
+            self,
+            SubmissionStatus::Pending | SubmissionStatus::Committed
+        )
+    }
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Mission {
+    pub id: u64,
+    pub owner: Address,
+    pub title: String,
+    pub description_cid: String,
+    pub reward_token: Address,
+    pub reward_amount: i128,
+    pub max_participants: u32,
+    pub participants_count: u32,
+    pub status: MissionStatus,
+    pub created_at: u64,
+    pub asset_requirements: Vec<AssetRequirement>,
+    pub asset_rule: GateRule,
+    pub stake_token: Option<Address>,
+    pub stake_amount: i128,
+    pub submission_deadline: u64,
+    pub review_deadline: u64,
+    pub review_window: u64,
+    pub auto_approve: bool,
+    /// Fee rate applied to each payout, fixed when the mission is created.
+    pub payout_fee_bps: u32,
+    /// Prize tiers, in ranking order, paid ahead of the base `reward_amount`.
+    pub reward_tiers: Vec<RewardTier>,
+    /// Payouts made so far from each entry of `reward_tiers`.
+    pub tier_paid: Vec<u32>,
+    pub reputation_gate: ReputationGate,
+    pub allowlist: Allowlist,
+    /// End of the reveal window for commit-reveal missions, or 0 when hunters
+    /// submit their CID directly.
+    pub reveal_deadline: u64,
+    /// Winners are drawn at random from eligible submissions by `draw_winners`
+    /// instead of being picked by reviewers.
+    pub raffle: bool,
+}
+
+/// Mission layout from before storage versioning (schema 1). These entries
+/// were written bare, without a `VersionedMission` wrapper.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissionV1 {
     pub id: u64,
     pub owner: Address,
     pub title: String,
@@ -39,15 +134,254 @@ pub struct Mission {
     pub min_asset_amount: i128,
 }
 
+impl MissionV1 {
+    /// Legacy missions keep their open-ended terms: no deadlines and no payout
+    /// fee. Hunters chose their own stake token under the old code and it was
+    /// never recorded, so stakes are frozen (`stake_token: None`) and returned
+    /// through `settle_legacy_stake` instead.
+    pub fn migrate(self, env: &Env) -> Mission {
+        let mut asset_requirements = Vec::new(env);
+        if let Some(token) = self.min_asset {
+            asset_requirements.push_back(AssetRequirement {
+                token,
+                min_amount: self.min_asset_amount,
+            });
+        }
+
+        Mission {
+            id: self.id,
+            owner: self.owner,
+            title: self.title,
+            description_cid: self.description_cid,
+            reward_token: self.reward_token,
+            reward_amount: self.reward_amount,
+            max_participants: self.max_participants,
+            participants_count: self.participants_count,
+            status: self.status,
+            created_at: self.created_at,
+            asset_requirements,
+            asset_rule: GateRule::All,
+            stake_token: None,
+            stake_amount: 0,
+            submission_deadline: u64::MAX,
+            review_deadline: u64::MAX,
+            review_window: u64::MAX,
+            auto_approve: false,
+            payout_fee_bps: 0,
+            reward_tiers: Vec::new(env),
+            tier_paid: Vec::new(env),
+            reputation_gate: ReputationGate::default(),
+            allowlist: Allowlist::Open,
+            reveal_deadline: 0,
+            raffle: false,
+        }
+    }
+}
+
+/// Stored form of a mission. New writes always use the latest variant.
+/// Contract types cannot be boxed, so the variants differ in size.
+#[allow(clippy::large_enum_variant)]
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VersionedMission {
+    V1(MissionV1),
+    V2(Mission),
+}
+
+impl VersionedMission {
+    pub fn is_current(&self) -> bool {
+        matches!(self, VersionedMission::V2(_))
+    }
+
+    pub fn into_current(self, env: &Env) -> Mission {
+        match self {
+            VersionedMission::V1(mission) => mission.migrate(env),
+            VersionedMission::V2(mission) => mission,
+        }
+    }
+}
+
+/// Who may submit to a mission.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum Allowlist {
+    /// Anyone who passes the other gates.
+    #[default]
+    Open,
+    /// Only these addresses, stored on-chain; for small invite lists of at
+    /// most 50 entries.
+    Addresses(Vec<Address>),
+    /// Root of a Merkle tree of invitees. Leaves are `sha256(xdr(address))`
+    /// and each parent hashes its two children in ascending order.
+    MerkleRoot(BytesN<32>),
+}
+
+/// Minimum balance of `token` a hunter must hold to submit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetRequirement {
+    pub token: Address,
+    pub min_amount: i128,
+}
+
+/// How a mission's asset requirements combine.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Copy)]
+#[contracttype]
+pub enum GateRule {
+    /// Every requirement must be met.
+    #[default]
+    All,
+    /// At least one requirement must be met.
+    Any,
+}
+
+/// Reputation a hunter needs in the configured reputation contract.
+/// Meeting any one of the criteria that are set is enough; with none set the
+/// mission is not gated on reputation.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReputationGate {
+    pub min_score: Option<i64>,
+    pub min_missions_completed: Option<u32>,
+    /// An unrevoked attestation of this type, issued by `attestation_issuer`.
+    pub attestation_type: Option<String>,
+    /// The only issuer whose attestations satisfy `attestation_type`; required
+    /// whenever a type is set, since anyone can issue attestations.
+    pub attestation_issuer: Option<Address>,
+}
+
+impl ReputationGate {
+    pub fn is_set(&self) -> bool {
+        self.min_score.is_some()
+            || self.min_missions_completed.is_some()
+            || self.attestation_type.is_some()
+    }
+}
+
+/// `count` reward slots paying `amount` each.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardTier {
+    pub amount: i128,
+    pub count: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Submission {
+    pub hunter: Address,
+    /// Empty until a committed submission is revealed.
+    pub ipfs_cid: String,
+    pub status: SubmissionStatus,
+    /// For commit-reveal missions, the time of the reveal.
+    pub submitted_at: u64,
+    /// `sha256(cid || salt)` for commit-reveal missions.
+    pub commitment: Option<BytesN<32>>,
+}
+
+/// Submission layout from before storage versioning (schema 1), written bare.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubmissionV1 {
     pub hunter: Address,
     pub ipfs_cid: String,
     pub status: SubmissionStatus,
     pub submitted_at: u64,
 }
 
+/// Stored form of a submission. New writes always use the latest variant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VersionedSubmission {
+    V1(SubmissionV1),
+    V2(Submission),
+}
+
+impl VersionedSubmission {
+    pub fn into_current(self) -> Submission {
+        match self {
+            VersionedSubmission::V1(submission) => Submission {
+                hunter: submission.hunter,
+                ipfs_cid: submission.ipfs_cid,
+                status: submission.status,
+                submitted_at: submission.submitted_at,
+                commitment: None,
+            },
+            VersionedSubmission::V2(submission) => submission,
+        }
+    }
+}
+
+/// Outcome of a raffle draw, kept for auditing. Replaying
+/// `prng.seed(seed)` and a shuffle over the entrant list at draw time
+/// reproduces the winner order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RaffleDraw {
+    pub seed: BytesN<32>,
+    pub entrants: Vec<Address>,
+    pub winners: Vec<Address>,
+    pub drawn_at: u64,
+}
+
+/// Reward approved for a hunter and awaiting `claim_reward`.
+/// The hunter's stake, if any, is returned by the same claim.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimableBalance {
+    pub mission_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub stake_token: Option<Address>,
+    pub stake_amount: i128,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Copy)]
+#[contracttype]
+pub enum SlashStatus {
+    /// Stake held by the contract while the dispute window is open.
+    #[default]
+    Pending,
+    /// The hunter appealed; awaiting the arbiter.
+    Appealed,
+    /// Stake sent to the treasury.
+    Upheld,
+    /// Stake returned to the hunter.
+    Overturned,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashRecord {
+    pub token: Address,
+    pub amount: i128,
+    pub reason_cid: String,
+    pub slashed_at: u64,
+    pub evidence_cid: Option<String>,
+    pub status: SlashStatus,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Copy)]
+#[contracttype]
+pub enum FeeMode {
+    /// Charged on top of the escrow when a mission is created.
+    #[default]
+    OnFunding,
+    /// Deducted from each reward when it is paid out.
+    OnPayout,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    pub fee_bps: u32,
+    /// Maximum fee per charge, in token units. Zero means uncapped.
+    pub max_fee: i128,
+    pub mode: FeeMode,
+    /// Share of the stake kept when a hunter withdraws a submission.
+    pub withdrawal_fee_bps: u32,
+}
+
 #[contracttype]
 pub enum DataKey {
     Mission(u64),
@@ -55,4 +389,39 @@ pub enum DataKey {
     Submission(u64, Address),
     HunterStake(u64, Address),
     Treasury,
+    SubmissionCount(u64),
+    /// Submissions of a mission that are still open; see `SubmissionStatus::is_open`.
+    OpenSubmissions(u64),
+    SubmissionIndex(u64, u32),
+    OwnerMissionCount(Address),
+    OwnerMission(Address, u32),
+    FeeConfig,
+    FeeExempt(Address),
+    Claimable(u64, Address),
+    HunterClaims(Address),
+    Slash(u64, Address),
+    /// Total of a token held on behalf of owners and hunters.
+    Liabilities(Address),
+    /// Share of `Liabilities` held for one mission: its unpaid rewards,
+    /// approved claims, held stakes and pending slashes.
+    Escrow(u64, Address),
+    /// Set when a hunter withdraws, so a resubmission reuses their index slot.
+    Withdrawn(u64, Address),
+    RaffleEntrants(u64),
+    RaffleDraw(u64),
+    Admin,
+    PendingAdmin,
+    Paused,
+    /// Only vetted tokens may back new missions while this is set.
+    TokenAllowlistEnabled,
+    VettedToken(Address),
+    SchemaVersion,
+    MigrationCursor,
+    Arbiter,
+    Reviewer(u64, Address),
+    /// Accounts holding a `Reviewer` entry on a mission, for `bump_mission`.
+    Reviewers(u64),
+    ReputationContract,
+    /// Set once a mission's owner has been credited with `missions_created`.
+    OwnerCredited(u64),
 }